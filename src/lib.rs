@@ -71,6 +71,14 @@
 //! const COLORS: &[(u8, u8, u8)] = concat_slices!([(u8, u8, u8)]: PRIMARIES, SECONDARIES);
 //! ```
 //!
+//! ## Element type requirements
+//!
+//! Trait methods cannot be called in `const` contexts, so the macros that
+//! compare elements use the built-in operators such as `<` and `==` directly.
+//! These only work in `const` for primitive types, so the element type of such
+//! a macro must be a primitive type like an integer, a `bool` or a `char`. The
+//! documentation of each of these macros names the operator it uses.
+//!
 //! [`std::concat!`]: core::concat
 //! [`std::concat_bytes!`]: core::concat_bytes
 
@@ -93,14 +101,88 @@ pub use core;
 /// each expression is converted to a byte slice and concatenated using
 /// [`concat_slices!`].
 ///
-/// See the [crate documentation][crate] for examples.
+/// An operand can be prefixed with `upper:` or `lower:` to convert just that
+/// operand to ASCII uppercase or lowercase before it is concatenated.
+/// Non-ASCII characters are passed through unchanged.
+///
+/// ```
+/// # use constcat::concat;
+/// #
+/// const NAME: &str = "Acme";
+/// const ENV: &str = concat!(upper: NAME, "_", lower: "HOME", "_DIR");
+/// assert_eq!(ENV, "ACME_home_DIR");
+/// ```
+///
+/// See the [crate documentation][crate] for more examples.
 ///
 /// [`std::concat!`]: core::concat
 #[macro_export]
 macro_rules! concat {
     ($($e:expr),* $(,)?) => {
         $crate::_concat!($($e),*)
+    };
+
+    ($($tt:tt)*) => {
+        $crate::_concat_modifiers!([] $($tt)*)
+    };
+}
+
+/// Applies the operand modifiers of `concat!`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_modifiers {
+    ([$($done:tt)*] $(,)?) => {
+        $crate::concat!($($done)*)
+    };
+
+    // Fast path to keep long operand lists within the recursion limit.
+    (
+        [$($done:tt)*]
+        $e0:expr, $e1:expr, $e2:expr, $e3:expr, $e4:expr, $e5:expr, $e6:expr, $e7:expr,
+        $($rest:tt)*
+    ) => {
+        $crate::_concat_modifiers!([$($done)* $e0, $e1, $e2, $e3, $e4, $e5, $e6, $e7,] $($rest)*)
+    };
+
+    ([$($done:tt)*] upper: $e:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_modifiers!([$($done)* $crate::_ascii_case!(true, $e),] $($($rest)*)?)
+    };
+
+    ([$($done:tt)*] lower: $e:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_modifiers!([$($done)* $crate::_ascii_case!(false, $e),] $($($rest)*)?)
+    };
+
+    ([$($done:tt)*] $e:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_modifiers!([$($done)* $e,] $($($rest)*)?)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _ascii_case {
+    ($upper:expr, $e:expr) => {{
+        const ARR: [u8; $crate::_maybe_std_concat!($e).len()] =
+            $crate::_ascii_case($crate::_maybe_std_concat!($e), $upper);
+        // SAFETY: Only ASCII characters are replaced, and only with ASCII
+        // characters, so the result is still valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _ascii_case<const N: usize>(s: &str, upper: bool) -> [u8; N] {
+    let bytes = s.as_bytes();
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        arr[i] = if upper {
+            bytes[i].to_ascii_uppercase()
+        } else {
+            bytes[i].to_ascii_lowercase()
+        };
+        i += 1;
     }
+    arr
 }
 
 #[doc(hidden)]
@@ -205,9 +287,9 @@ macro_rules! _maybe_std_concat_bytes {
 
 /// Concatenate `const` [`&[T]`][slice] expressions into a static slice.
 ///
-/// This macro takes any number of comma-separated [`&[T]`][slice] expressions
-/// and yields an expression of type [`&'static [T]`][slice] which is the result
-/// of all of the expressions concatenated left-to-right.
+/// This macro takes any number of comma-separated [`&[T]`][slice] or `[T; N]`
+/// expressions and yields an expression of type [`&'static [T]`][slice] which
+/// is the result of all of the expressions concatenated left-to-right.
 ///
 /// # Notes
 ///
@@ -233,13 +315,154 @@ macro_rules! _maybe_std_concat_bytes {
 ///
 ///   concat_slices!([i256]: /* ... */);
 ///   ```
+/// - Types with validity invariants like `char` and `bool` are supported. The
+///   output only ever contains elements copied from the inputs, which are
+///   already valid values of the type.
+///
+///   ```
+///   # use constcat::concat_slices;
+///   const VOWELS: &[char] = concat_slices!([char]: &['a', 'e', 'i'], &['o', 'u']);
+///   ```
+/// - Arrays can be passed by value, e.g. from a `const fn` that returns an
+///   array, without first binding them to a constant. Such an operand is
+///   evaluated once to fill the result, not once per element.
+///
+///   ```
+///   # use constcat::concat_slices;
+///   const fn header() -> [u8; 4] { *b"HEAD" }
+///   const TAIL: &[u8] = b"TAIL";
+///   const BLOB: &[u8] = concat_slices!([u8]: header(), TAIL);
+///   ```
+///
+/// - An operand can be repeated inline by following it with `* count`, where
+///   `count` is a `const` [`usize`]. The repeated operand must be a single
+///   token tree, such as an identifier or a parenthesized expression,
+///   optionally preceded by `&`. A count that is not a single token tree must
+///   be parenthesized.
+///
+///   ```
+///   # use constcat::concat_slices;
+///   const SEP: &[u8] = &[0];
+///   const BLOB: &[u8] = concat_slices!([u8]: &[1], SEP * 3, &[2, 3] * (1 + 1));
+///   assert_eq!(BLOB, [1, 0, 0, 0, 2, 3, 2, 3]);
+///   ```
 ///
 /// See the [crate documentation][crate] for examples.
 #[macro_export]
 macro_rules! concat_slices {
-    ([$T:ty]: $($s:expr),* $(,)?) => {
-        $crate::_concat_slices!([$T]: $($s),*)
+    ([$T:ty]: $($s:tt)*) => {
+        $crate::_concat_slices_rep!(@scan [$T] [$($s)*] $($s)*)
+    };
+}
+
+/// Parses the operands of `concat_slices!` into `operand => count` pairs.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_slices_rep {
+    // Look for a `*` at the top level, eight tokens at a time. Without one
+    // there can be no repetition so the operands are parsed as plain
+    // expressions, which has no per-operand cost in recursion depth.
+    (@scan [$T:ty] [$($all:tt)*]) => {
+        $crate::_concat_slices_rep!(@plain [$T] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt $d:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!([$T] [] $($all)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $b:tt $c:tt $d:tt $e:tt $f:tt $g:tt $h:tt $($rest:tt)*) => {
+        $crate::_concat_slices_rep!(@scan [$T] [$($all)*] $($rest)*)
+    };
+    (@scan [$T:ty] [$($all:tt)*] $a:tt $($rest:tt)*) => {
+        $crate::_concat_slices_rep!(@scan [$T] [$($all)*] $($rest)*)
+    };
+
+    (@plain [$T:ty] $($s:expr),* $(,)?) => {
+        $crate::_concat_slices!([$T]: $($s => 1,)*)
+    };
+
+    ([$T:ty] [$($done:tt)*] $(,)?) => {
+        $crate::_concat_slices!([$T]: $($done)*)
+    };
+
+    // Fast paths that take several operands at a time so that long operand
+    // lists stay well within the recursion limit. An operand immediately
+    // followed by a comma cannot have a repetition count.
+    (
+        [$T:ty] [$($done:tt)*]
+        $s0:tt, $s1:tt, $s2:tt, $s3:tt, $s4:tt, $s5:tt, $s6:tt, $s7:tt,
+        $($rest:tt)*
+    ) => {
+        $crate::_concat_slices_rep!(
+            [$T] [
+                $($done)*
+                $s0 => 1, $s1 => 1, $s2 => 1, $s3 => 1,
+                $s4 => 1, $s5 => 1, $s6 => 1, $s7 => 1,
+            ]
+            $($rest)*
+        )
+    };
+
+    (
+        [$T:ty] [$($done:tt)*]
+        &$s0:tt, &$s1:tt, &$s2:tt, &$s3:tt, &$s4:tt, &$s5:tt, &$s6:tt, &$s7:tt,
+        $($rest:tt)*
+    ) => {
+        $crate::_concat_slices_rep!(
+            [$T] [
+                $($done)*
+                &$s0 => 1, &$s1 => 1, &$s2 => 1, &$s3 => 1,
+                &$s4 => 1, &$s5 => 1, &$s6 => 1, &$s7 => 1,
+            ]
+            $($rest)*
+        )
+    };
+
+    ([$T:ty] [$($done:tt)*] &$s:tt * $n:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_slices_rep!([$T] [$($done)* &$s => $n,] $($($rest)*)?)
+    };
+
+    // A bare `&` or `*` is the start of an operand like `&*X`, the `*` that
+    // follows it is a dereference and not a repetition.
+    ([$T:ty] [$($done:tt)*] & * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!(@expr [$T] [$($done)*] & * $($rest)*)
+    };
+
+    ([$T:ty] [$($done:tt)*] * * $($rest:tt)*) => {
+        $crate::_concat_slices_rep!(@expr [$T] [$($done)*] * * $($rest)*)
+    };
+
+    ([$T:ty] [$($done:tt)*] $s:tt * $n:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_slices_rep!([$T] [$($done)* $s => $n,] $($($rest)*)?)
+    };
+
+    ([$T:ty] [$($done:tt)*] $s:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_slices_rep!([$T] [$($done)* $s => 1,] $($($rest)*)?)
+    };
+
+    (@expr [$T:ty] [$($done:tt)*] $s:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_slices_rep!([$T] [$($done)* $s => 1,] $($($rest)*)?)
     };
+
 }
 
 #[doc(hidden)]
@@ -250,26 +473,37 @@ macro_rules! _concat_slices {
         &ARR
     }};
 
-    ([$T:ty]: $($s:expr),+) => {{
+    ([$T:ty]: $($s:expr => $n:expr,)+) => {{
         $(
-            const _: &[$T] = $s; // require constants
+            // require arrays or slices of `T`
+            const _: () = {
+                let _ = |i: usize| {
+                    let _: $T = $s[i];
+                };
+            };
         )*
-        const LEN: usize = $( $s.len() + )* 0;
+        const LEN: usize = $( $s.len() * $n + )* 0;
         const ARR: [$T; LEN] = {
             use $crate::core::mem::MaybeUninit;
-            let mut arr: [MaybeUninit<$T>; LEN] = [MaybeUninit::zeroed(); LEN];
+            let mut arr: [MaybeUninit<$T>; LEN] = [MaybeUninit::uninit(); LEN];
             let mut base: usize = 0;
             $({
-                let mut i = 0;
-                while i < $s.len() {
-                    // Ideally this should use `MaybeUninit::write` once it is
-                    // made const.
-                    // Documentation: https://doc.rust-lang.org/core/mem/union.MaybeUninit.html#method.write
-                    // Tracking issue: https://github.com/rust-lang/rust/issues/63567
-                    arr[base + i] = MaybeUninit::new($s[i]);
-                    i += 1;
+                // evaluate the operand once rather than once per element
+                const OPERAND: &[$T] = $s.split_at(0).1;
+                let mut r = 0;
+                while r < $n {
+                    let mut i = 0;
+                    while i < OPERAND.len() {
+                        // Ideally this should use `MaybeUninit::write` once it
+                        // is made const.
+                        // Documentation: https://doc.rust-lang.org/core/mem/union.MaybeUninit.html#method.write
+                        // Tracking issue: https://github.com/rust-lang/rust/issues/63567
+                        arr[base + i] = MaybeUninit::new(OPERAND[i]);
+                        i += 1;
+                    }
+                    base += OPERAND.len();
+                    r += 1;
                 }
-                base += $s.len();
             })*
             if base != LEN { panic!("invalid length"); }
 
@@ -293,3 +527,7198 @@ macro_rules! _concat_slices {
         &ARR
     }};
 }
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_wide!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` wide character slices into a static slice.
+///
+/// This macro is a front-end for [`concat_slices!`] which only accepts `u16`,
+/// `u32` and `u64` element types. It takes any number of comma-separated
+/// [`&[u16]`][slice], [`&[u32]`][slice] or [`&[u64]`][slice] expressions and
+/// yields an expression of type `&'static [u16]` (or `u32`, `u64`
+/// respectively) which is the result of all of the expressions concatenated
+/// left-to-right.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_wide;
+/// #
+/// const HELLO: &[u16] = &[0x48, 0x65, 0x6C, 0x6C, 0x6F];
+/// const WORLD: &[u16] = &[0x57, 0x6F, 0x72, 0x6C, 0x64];
+/// const WIDE: &[u16] = concat_wide!([u16]: HELLO, &[0x20], WORLD);
+/// ```
+///
+/// # Soundness
+///
+/// Every element of the scratch array used by [`concat_slices!`] is written
+/// before the array is transmuted, so no uninitialized integers are ever
+/// read. The resulting array has the same alignment as any other array of the
+/// element type.
+#[macro_export]
+macro_rules! concat_wide {
+    ([u16]: $($s:expr),* $(,)?) => {
+        $crate::concat_slices!([u16]: $($s),*)
+    };
+    ([u32]: $($s:expr),* $(,)?) => {
+        $crate::concat_slices!([u32]: $($s),*)
+    };
+    ([u64]: $($s:expr),* $(,)?) => {
+        $crate::concat_slices!([u64]: $($s),*)
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_utf16!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// UTF-16 encoded slice.
+///
+/// This macro takes any number of comma-separated literals or constant
+/// expressions, concatenates them using [`concat!`] and yields an expression of
+/// type [`&'static [u16]`][slice] which is the UTF-16 encoding of the result.
+/// Characters outside of the Basic Multilingual Plane are encoded as surrogate
+/// pairs.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_utf16;
+/// #
+/// const NAME: &str = "constcat";
+/// const WIDE: &[u16] = concat_utf16!(NAME, " 🎉");
+/// assert_eq!(WIDE, "constcat 🎉".encode_utf16().collect::<Vec<_>>());
+/// ```
+#[macro_export]
+macro_rules! concat_utf16 {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_utf16_len(SRC);
+        const ARR: [u16; LEN] = $crate::_utf16::<LEN>(SRC);
+        &ARR
+    }};
+}
+
+/// Decodes the UTF-8 encoded character starting at byte `i`, returning the
+/// code point and the number of bytes it was encoded with.
+#[doc(hidden)]
+pub const fn _decode_utf8(bytes: &[u8], i: usize) -> (u32, usize) {
+    let b = bytes[i] as u32;
+    if b < 0x80 {
+        (b, 1)
+    } else if b < 0xE0 {
+        (((b & 0x1F) << 6) | (bytes[i + 1] as u32 & 0x3F), 2)
+    } else if b < 0xF0 {
+        (
+            ((b & 0x0F) << 12) | ((bytes[i + 1] as u32 & 0x3F) << 6) | (bytes[i + 2] as u32 & 0x3F),
+            3,
+        )
+    } else {
+        (
+            ((b & 0x07) << 18)
+                | ((bytes[i + 1] as u32 & 0x3F) << 12)
+                | ((bytes[i + 2] as u32 & 0x3F) << 6)
+                | (bytes[i + 3] as u32 & 0x3F),
+            4,
+        )
+    }
+}
+
+#[doc(hidden)]
+pub const fn _utf16_len(s: &str) -> usize {
+    let bytes = s.as_bytes();
+    let mut len = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, n) = _decode_utf8(bytes, i);
+        len += if c >= 0x10000 { 2 } else { 1 };
+        i += n;
+    }
+    len
+}
+
+#[doc(hidden)]
+pub const fn _utf16<const N: usize>(s: &str) -> [u16; N] {
+    let bytes = s.as_bytes();
+    let mut arr = [0; N];
+    let mut i = 0;
+    let mut j = 0;
+    while i < bytes.len() {
+        let (c, n) = _decode_utf8(bytes, i);
+        if c >= 0x10000 {
+            let c = c - 0x10000;
+            arr[j] = 0xD800 | (c >> 10) as u16;
+            arr[j + 1] = 0xDC00 | (c & 0x3FF) as u16;
+            j += 2;
+        } else {
+            arr[j] = c as u16;
+            j += 1;
+        }
+        i += n;
+    }
+    if j != N {
+        panic!("invalid length");
+    }
+    arr
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_ascending!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// assert that the result is strictly ascending.
+///
+/// This macro works exactly like [`concat_slices!`] except that it fails to
+/// compile if any element of the result is not strictly greater than the
+/// element before it. The elements are not reordered, this only validates the
+/// order that they were given in. The panic message names the index of the
+/// first out-of-order element.
+///
+/// The element type must support the `<` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_ascending;
+/// #
+/// const LOWER: &[u32] = &[1, 2, 3];
+/// const UPPER: &[u32] = &[10, 20, 30];
+/// const TABLE: &[u32] = concat_ascending!([u32]: LOWER, UPPER);
+/// ```
+///
+/// The following fails to compile because `3` appears after `10`.
+///
+/// ```compile_fail
+/// # use constcat::concat_ascending;
+/// #
+/// const TABLE: &[u32] = concat_ascending!([u32]: &[1, 10], &[3]);
+/// ```
+#[macro_export]
+macro_rules! concat_ascending {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const ARR: &[$T] = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let mut i = 1;
+            while i < arr.len() {
+                if !(arr[i - 1] < arr[i]) {
+                    $crate::_Msg::new()
+                        .str("element at index ")
+                        .usize(i)
+                        .str(" is not strictly greater than the previous element")
+                        .panic();
+                }
+                i += 1;
+            }
+            arr
+        };
+        ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_int_csv!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Format `const` integer expressions as decimal and join them into a static
+/// string slice.
+///
+/// This macro takes a separator in the form `sep = <expr>; ` followed by any
+/// number of comma-separated integer expressions and yields an expression of
+/// type [`&'static str`][str] which is the result of formatting each integer as
+/// decimal and joining them left-to-right with the separator. The integers may
+/// be of any width or signedness, negative values are prefixed with a `-`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_int_csv;
+/// #
+/// const MAX: u64 = u64::MAX;
+/// const MIN: i8 = i8::MIN;
+/// const CSV: &str = concat_int_csv!(sep = ","; 1, 22, 333, MAX, MIN);
+/// assert_eq!(CSV, "1,22,333,18446744073709551615,-128");
+/// ```
+#[macro_export]
+macro_rules! concat_int_csv {
+    (sep = $sep:expr; $($e:expr),* $(,)?) => {{
+        const INTS: &[(bool, u128)] = &[$($crate::_int!($e)),*];
+        const LEN: usize = $crate::_int_csv::<0>(INTS, $sep).len();
+        const ARR: [u8; LEN] = $crate::_int_csv::<LEN>(INTS, $sep).finish();
+        // SAFETY: The array only contains ASCII digits, `-`, and the bytes of
+        // the separator string so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _int_csv<const N: usize>(ints: &[(bool, u128)], sep: &str) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < ints.len() {
+        if i > 0 {
+            buf = buf.str(sep);
+        }
+        buf = buf.int(ints[i]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_join_char!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with exactly one separator character at each join.
+///
+/// This macro takes a separator `char` followed by a semicolon and then any
+/// number of comma-separated literals or constant expressions. It yields an
+/// expression of type [`&'static str`][str] which is the result of all of the
+/// expressions concatenated left-to-right, except that each boundary between
+/// two operands contains exactly one separator. If there is no separator at a
+/// boundary one is inserted, and if there is a run of separators touching a
+/// boundary it is collapsed into one.
+///
+/// Only the runs of separators that touch a boundary are affected. Separators
+/// in the middle of an operand, and at the very start and end of the result,
+/// are left as is. Empty operands are ignored and do not create a boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_join_char;
+/// #
+/// const BASE: &str = "https://example.com/";
+/// const URL: &str = concat_join_char!('/'; BASE, "/api/", "v1", "users");
+/// assert_eq!(URL, "https://example.com/api/v1/users");
+///
+/// const MODULE: &str = concat_join_char!('.'; "crate", ".inner", "Type");
+/// assert_eq!(MODULE, "crate.inner.Type");
+/// ```
+#[macro_export]
+macro_rules! concat_join_char {
+    ($c:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LENS: &[usize] = &[$($crate::_maybe_std_concat!($e).len()),*];
+        const LEN: usize = $crate::_join_char::<0>(SRC, LENS, $c).len();
+        const ARR: [u8; LEN] = $crate::_join_char::<LEN>(SRC, LENS, $c).finish();
+        // SAFETY: The source string is valid UTF-8 and only whole encoded
+        // separator characters are inserted or removed.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+/// Returns whether `pos` is a boundary between two non-empty operands.
+const fn _is_join(lens: &[usize], pos: usize) -> bool {
+    let mut offset = 0;
+    let mut seen = false;
+    let mut i = 0;
+    while i < lens.len() {
+        if lens[i] > 0 {
+            if seen && offset == pos {
+                return true;
+            }
+            seen = true;
+            offset += lens[i];
+        }
+        i += 1;
+    }
+    false
+}
+
+#[doc(hidden)]
+pub const fn _join_char<const N: usize>(src: &str, lens: &[usize], c: char) -> _Buf<N> {
+    let sep = _Buf::<4>::new().char(c);
+    let sep = sep.as_bytes();
+    let bytes = src.as_bytes();
+    let mut buf = _Buf::new();
+    let mut p = 0;
+    while p < bytes.len() {
+        if _starts_with_at(bytes, p, sep) {
+            let mut q = p;
+            let mut joins = false;
+            while _starts_with_at(bytes, q, sep) {
+                joins = joins || _is_join(lens, q);
+                q += sep.len();
+            }
+            if joins || _is_join(lens, q) {
+                buf = buf.bytes(sep);
+            } else {
+                buf = buf.bytes(_subslice(bytes, p, q));
+            }
+            p = q;
+        } else {
+            if _is_join(lens, p) && !(p >= sep.len() && _starts_with_at(bytes, p - sep.len(), sep))
+            {
+                buf = buf.bytes(sep);
+            }
+            buf = buf.push(bytes[p]);
+            p += 1;
+        }
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// static_map!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Build a `const` [`StaticMap`] from [`&str`][str] key and value pairs.
+///
+/// This macro takes any number of comma-separated `key => value` pairs where
+/// each key and value is a literal or constant expression as accepted by
+/// [`concat!`]. It yields an expression of type [`StaticMap`] which stores all
+/// of the keys and values concatenated into a single static string slice
+/// together with an index of offsets into it.
+///
+/// Keys must be unique, the macro fails to compile if the same key is given
+/// more than once.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{static_map, StaticMap};
+/// #
+/// const GREEN: &str = "#00ff00";
+/// const COLORS: StaticMap = static_map! {
+///     "red" => "#ff0000",
+///     "green" => GREEN,
+///     "blue" => "#0000ff",
+/// };
+///
+/// const RED: Option<&str> = COLORS.get("red");
+/// assert_eq!(RED, Some("#ff0000"));
+/// assert_eq!(COLORS.get("green"), Some("#00ff00"));
+/// assert_eq!(COLORS.get("pink"), None);
+/// ```
+#[macro_export]
+macro_rules! static_map {
+    ($($k:expr => $v:expr),* $(,)?) => {{
+        const DATA: &str = $crate::concat!($($k, $v),*);
+        const LENS: &[usize] = &[
+            $($crate::_maybe_std_concat!($k).len(), $crate::_maybe_std_concat!($v).len()),*
+        ];
+        const OFFSETS: [usize; LENS.len() + 1] = $crate::_static_map_offsets(DATA, LENS);
+        $crate::StaticMap::_new(DATA, &OFFSETS)
+    }};
+}
+
+/// A `const` string map built using [`static_map!`].
+///
+/// All of the keys and values are stored in a single static string slice, the
+/// map also stores the offset of each key and value within it. Lookups are a
+/// linear scan over the keys which is fast for the small tables this is
+/// intended for and can be performed in `const` contexts.
+#[derive(Debug, Clone, Copy)]
+pub struct StaticMap {
+    data: &'static str,
+    // The start offset of each key and value followed by the total length.
+    offsets: &'static [usize],
+}
+
+impl StaticMap {
+    #[doc(hidden)]
+    pub const fn _new(data: &'static str, offsets: &'static [usize]) -> Self {
+        Self { data, offsets }
+    }
+
+    /// Returns the value for the given key if it is present in the map.
+    pub const fn get(&self, key: &str) -> Option<&'static str> {
+        let mut i = 0;
+        while i < self.len() {
+            if _bytes_eq(self.entry(2 * i).as_bytes(), key.as_bytes()) {
+                return Some(self.entry(2 * i + 1));
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns whether the map contains the given key.
+    pub const fn contains_key(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Returns the number of entries in the map.
+    pub const fn len(&self) -> usize {
+        self.offsets.len() / 2
+    }
+
+    /// Returns whether the map has no entries.
+    pub const fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the `i`th key or value in the backing store.
+    const fn entry(&self, i: usize) -> &'static str {
+        let bytes = _subslice(self.data.as_bytes(), self.offsets[i], self.offsets[i + 1]);
+        // SAFETY: The offsets were computed from the lengths of the original
+        // string slices so they always lie on character boundaries.
+        unsafe { core::str::from_utf8_unchecked(bytes) }
+    }
+}
+
+#[doc(hidden)]
+pub const fn _static_map_offsets<const N: usize>(data: &str, lens: &[usize]) -> [usize; N] {
+    let mut offsets = [0; N];
+    let mut i = 0;
+    while i < lens.len() {
+        offsets[i + 1] = offsets[i] + lens[i];
+        i += 1;
+    }
+    if offsets[N - 1] != data.len() {
+        panic!("invalid length");
+    }
+    let mut i = 0;
+    while i < lens.len() {
+        let mut j = i + 2;
+        while j < lens.len() {
+            let a = _subslice(data.as_bytes(), offsets[i], offsets[i + 1]);
+            let b = _subslice(data.as_bytes(), offsets[j], offsets[j + 1]);
+            if _bytes_eq(a, b) {
+                _Msg::new()
+                    .str("duplicate key at index ")
+                    .usize(j / 2)
+                    .panic();
+            }
+            j += 2;
+        }
+        i += 2;
+    }
+    offsets
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// key_bytes!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte array reference of an exact length.
+///
+/// This macro takes the expected length followed by a colon and then any
+/// number of comma-separated expressions as accepted by [`concat_bytes!`]. It
+/// yields an expression of type `&'static [u8; N]` which is the result of all
+/// of the expressions concatenated left-to-right. If the total length is not
+/// exactly `N` the macro fails to compile, so key material can never be
+/// silently truncated or padded.
+///
+/// The length must be a single token, e.g. an integer literal or the name of a
+/// constant.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::key_bytes;
+/// #
+/// const TAG: &[u8] = b"constcat:v1:";
+/// const SEED: &[u8; 20] = &[7; 20];
+/// const KEY: &[u8; 32] = key_bytes!(32: TAG, SEED);
+/// ```
+///
+/// The following fails to compile because the fragments are only 31 bytes.
+///
+/// ```compile_fail
+/// # use constcat::key_bytes;
+/// #
+/// const TAG: &[u8] = b"constcat:v1:";
+/// const KEY: &[u8; 32] = key_bytes!(32: TAG, &[7; 19]);
+/// ```
+#[macro_export]
+macro_rules! key_bytes {
+    ($n:tt: $($e:expr),* $(,)?) => {{
+        const ARR: [u8; $n] = $crate::_key_bytes($crate::concat_bytes!($($e),*));
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _key_bytes<const N: usize>(bytes: &[u8]) -> [u8; N] {
+    if bytes.len() != N {
+        _Msg::new()
+            .str("expected ")
+            .usize(N)
+            .str(" bytes of key material but the concatenation is ")
+            .usize(bytes.len())
+            .str(" bytes")
+            .panic();
+    }
+    _Buf::new().bytes(bytes).finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_map!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice, transforming each byte with a `const fn`.
+///
+/// This macro takes a path to a `const fn(u8) -> u8` followed by a semicolon
+/// and then any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`]. It yields an expression of type
+/// [`&'static [u8]`][slice] which is the result of all of the expressions
+/// concatenated left-to-right with the function applied to every byte of the
+/// result.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_map;
+/// #
+/// const fn mask(b: u8) -> u8 { b ^ 0xFF }
+/// const MASKED: &[u8] = concat_bytes_map!(mask; &[0x00, 0x0F], &[0xFF]);
+/// assert_eq!(MASKED, [0xFF, 0xF0, 0x00]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_map {
+    ($f:path; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = SRC.len();
+        const ARR: [u8; LEN] = {
+            let mut arr = [0; LEN];
+            let mut i = 0;
+            while i < LEN {
+                arr[i] = $f(SRC[i]);
+                i += 1;
+            }
+            arr
+        };
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_xor!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice, XORing each byte with a mask or repeating key.
+///
+/// This macro takes either `mask = <u8>; ` or `key = <&[u8]>; ` followed by any
+/// number of comma-separated expressions as accepted by [`concat_bytes!`]. It
+/// yields an expression of type [`&'static [u8]`][slice] which is the result
+/// of all of the expressions concatenated left-to-right with every byte XORed
+/// with the mask. When a key is given the `i`th byte is XORed with
+/// `key[i % key.len()]`. The key must not be empty.
+///
+/// XOR is its own inverse so the data can be decoded by passing the encoded
+/// slice back through this macro with the same mask or key.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_xor;
+/// #
+/// const SECRET: &[u8] = b"hunter2";
+/// const ENCODED: &[u8] = concat_bytes_xor!(mask = 0x5A; SECRET, &[0x00]);
+/// assert_eq!(ENCODED, [0x32, 0x2F, 0x34, 0x2E, 0x3F, 0x28, 0x68, 0x5A]);
+///
+/// const KEY: &[u8] = &[0x01, 0x02];
+/// const STREAM: &[u8] = concat_bytes_xor!(key = KEY; SECRET);
+/// const DECODED: &[u8] = concat_bytes_xor!(key = KEY; STREAM);
+/// assert_eq!(DECODED, SECRET);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_xor {
+    (mask = $mask:expr; $($e:expr),* $(,)?) => {
+        $crate::concat_bytes_xor!(key = &[$mask]; $($e),*)
+    };
+    (key = $key:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const ARR: [u8; SRC.len()] = $crate::_xor(SRC, $key);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _xor<const N: usize>(src: &[u8], key: &[u8]) -> [u8; N] {
+    if key.is_empty() {
+        panic!("key must not be empty");
+    }
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        buf = buf.push(src[i] ^ key[i % key.len()]);
+        i += 1;
+    }
+    buf.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_at!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Place `const` [`&[u8]`][slice] expressions and literals at explicit offsets
+/// in a static byte slice.
+///
+/// This macro takes any number of comma-separated `(bytes, offset)` pairs where
+/// `bytes` is an expression as accepted by [`concat_bytes!`] and `offset` is a
+/// `usize`. It yields an expression of type [`&'static [u8]`][slice] which is
+/// just long enough to cover the furthest placed operand. Each operand is
+/// copied to its offset and any gaps are filled with zeroes.
+///
+/// The pairs may be given in any order, but the macro fails to compile if any
+/// two operands overlap. The panic message names the overlapping operands. An
+/// empty operand never overlaps and can be used to pad the result to a
+/// particular length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_at;
+/// #
+/// const MAGIC: &[u8] = &[0x7F, 0x45, 0x4C, 0x46];
+/// const VERSION: &[u8] = &[0x01];
+/// const HEADER: &[u8] = concat_bytes_at!((MAGIC, 0), (VERSION, 6));
+/// assert_eq!(HEADER, [0x7F, 0x45, 0x4C, 0x46, 0x00, 0x00, 0x01]);
+/// ```
+///
+/// The following fails to compile because both operands write to offset `3`.
+///
+/// ```compile_fail
+/// # use constcat::concat_bytes_at;
+/// #
+/// const HEADER: &[u8] = concat_bytes_at!((&[1, 2, 3, 4], 0), (&[5], 3));
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_at {
+    ($(($e:expr, $offset:expr)),* $(,)?) => {{
+        const PARTS: &[(&[u8], usize)] = &[$(($crate::concat_bytes!($e), $offset)),*];
+        const LEN: usize = $crate::_bytes_at_len(PARTS);
+        const ARR: [u8; LEN] = $crate::_bytes_at(PARTS);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _bytes_at_len(parts: &[(&[u8], usize)]) -> usize {
+    let mut len = 0;
+    let mut i = 0;
+    while i < parts.len() {
+        let (bytes, offset) = parts[i];
+        if offset + bytes.len() > len {
+            len = offset + bytes.len();
+        }
+        i += 1;
+    }
+    len
+}
+
+#[doc(hidden)]
+pub const fn _bytes_at<const N: usize>(parts: &[(&[u8], usize)]) -> [u8; N] {
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < parts.len() {
+        let (bytes, offset) = parts[i];
+        let mut j = 0;
+        while j < i {
+            let (other, other_offset) = parts[j];
+            let overlaps =
+                offset < other_offset + other.len() && other_offset < offset + bytes.len();
+            if overlaps && !bytes.is_empty() && !other.is_empty() {
+                _Msg::new()
+                    .str("operand ")
+                    .usize(i)
+                    .str(" at offset ")
+                    .usize(offset)
+                    .str(" overlaps operand ")
+                    .usize(j)
+                    .str(" at offset ")
+                    .usize(other_offset)
+                    .panic();
+            }
+            j += 1;
+        }
+        let mut k = 0;
+        while k < bytes.len() {
+            arr[offset + k] = bytes[k];
+            k += 1;
+        }
+        i += 1;
+    }
+    arr
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_cast!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[S]`][slice] expressions into a static slice,
+/// casting each element to another numeric type.
+///
+/// This macro requires the source and target types to be specified in the
+/// form `[S] -> T: ` before the comma separated expressions. It yields an
+/// expression of type [`&'static [T]`][slice] which is the result of all of
+/// the expressions concatenated left-to-right with each element converted
+/// using an `as` cast.
+///
+/// The cast semantics are exactly that of `as`, widening an unsigned integer
+/// zero-extends it, widening a signed integer sign-extends it, and narrowing
+/// truncates.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_cast;
+/// #
+/// const SAMPLES: &[u8] = &[0x00, 0x7F, 0xFF];
+/// const WIDE: &[u16] = concat_cast!([u8] -> u16: SAMPLES, &[0x10]);
+/// assert_eq!(WIDE, [0x0000, 0x007F, 0x00FF, 0x0010]);
+///
+/// const SIGNED: &[i8] = &[-1, 1];
+/// const EXTENDED: &[i32] = concat_cast!([i8] -> i32: SIGNED);
+/// assert_eq!(EXTENDED, [-1, 1]);
+/// ```
+#[macro_export]
+macro_rules! concat_cast {
+    ([$S:ty] -> $T:ty: $($s:expr),* $(,)?) => {{
+        const SRC: &[$S] = $crate::concat_slices!([$S]: $($s),*);
+        const LEN: usize = SRC.len();
+        const ARR: [$T; LEN] = {
+            let mut arr = [0 as $T; LEN];
+            let mut i = 0;
+            while i < LEN {
+                arr[i] = SRC[i] as $T;
+                i += 1;
+            }
+            arr
+        };
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_ascii!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that the result is ASCII.
+///
+/// This macro works exactly like [`concat!`] except that it fails to compile
+/// if the result contains any non-ASCII characters. The panic message names
+/// the byte index of the first non-ASCII character.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_ascii;
+/// #
+/// const PREFIX: &str = "X-";
+/// const HEADER: &str = concat_ascii!(PREFIX, "Request-Id");
+/// ```
+///
+/// The following fails to compile because `é` is not ASCII.
+///
+/// ```compile_fail
+/// # use constcat::concat_ascii;
+/// #
+/// const HEADER: &str = concat_ascii!("X-", "Café");
+/// ```
+#[macro_export]
+macro_rules! concat_ascii {
+    ($($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_ascii($crate::concat!($($e),*));
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _ascii(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii() {
+            _Msg::new()
+                .str("non-ASCII character at byte index ")
+                .usize(i)
+                .panic();
+        }
+        i += 1;
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_mark_every!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice,
+/// inserting a marker after every `n` elements.
+///
+/// This macro requires the type of slice, the interval and the marker to be
+/// specified in the form `[T]: n = <usize>, mark = <T>; ` before the comma
+/// separated expressions. It yields an expression of type
+/// [`&'static [T]`][slice] which is the result of all of the expressions
+/// concatenated left-to-right with the marker inserted after each full group
+/// of `n` data elements. A trailing group with fewer than `n` elements is not
+/// followed by a marker, so the output length is `len + len / n`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_mark_every;
+/// #
+/// const A: &[u8] = &[1, 2, 3];
+/// const B: &[u8] = &[4, 5];
+/// const FRAMED: &[u8] = concat_mark_every!([u8]: n = 2, mark = 0xAA; A, B);
+/// assert_eq!(FRAMED, [1, 2, 0xAA, 3, 4, 0xAA, 5]);
+/// ```
+#[macro_export]
+macro_rules! concat_mark_every {
+    ([$T:ty]: n = $n:expr, mark = $mark:expr; $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const LEN: usize = $crate::_mark_every_len(SRC.len(), $n);
+        const ARR: [$T; LEN] = {
+            let mut arr: [$T; LEN] = [$mark; LEN];
+            let mut i = 0;
+            while i < SRC.len() {
+                arr[i + i / $n] = SRC[i];
+                i += 1;
+            }
+            arr
+        };
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _mark_every_len(len: usize, n: usize) -> usize {
+    if n == 0 {
+        panic!("`n` must be greater than zero");
+    }
+    len + len / n
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_require_contains! and concat_forbid_contains!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that the result contains a substring.
+///
+/// This macro takes a `const` [`&str`][str] needle followed by a semicolon and
+/// then any number of comma-separated expressions as accepted by [`concat!`].
+/// It works exactly like [`concat!`] except that it fails to compile if the
+/// result does not contain the needle.
+///
+/// See [`concat_bytes_require_contains!`] for the byte slice equivalent.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_require_contains;
+/// #
+/// const LICENSE: &str = "SPDX-License-Identifier: MIT";
+/// const HEADER: &str = concat_require_contains!("SPDX"; "// ", LICENSE, "\n");
+/// ```
+///
+/// The following fails to compile because the header is missing the token.
+///
+/// ```compile_fail
+/// # use constcat::concat_require_contains;
+/// #
+/// const HEADER: &str = concat_require_contains!("SPDX"; "// ", "MIT", "\n");
+/// ```
+#[macro_export]
+macro_rules! concat_require_contains {
+    ($needle:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::concat!($($e),*);
+        const _: () = $crate::_require_contains(STR.as_bytes(), $crate::concat!($needle).as_bytes());
+        STR
+    }};
+}
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that the result does not contain a substring.
+///
+/// This macro takes a `const` [`&str`][str] needle followed by a semicolon and
+/// then any number of comma-separated expressions as accepted by [`concat!`].
+/// It works exactly like [`concat!`] except that it fails to compile if the
+/// result contains the needle. The panic message names the byte index of the
+/// first occurrence.
+///
+/// See [`concat_bytes_forbid_contains!`] for the byte slice equivalent.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_forbid_contains;
+/// #
+/// const NAME: &str = "production";
+/// const QUERY: &str = concat_forbid_contains!(";"; "SELECT * FROM ", NAME);
+/// ```
+///
+/// The following fails to compile because the query contains a `;`.
+///
+/// ```compile_fail
+/// # use constcat::concat_forbid_contains;
+/// #
+/// const QUERY: &str = concat_forbid_contains!(";"; "SELECT * FROM ", "t; DROP");
+/// ```
+#[macro_export]
+macro_rules! concat_forbid_contains {
+    ($needle:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::concat!($($e),*);
+        const _: () = $crate::_forbid_contains(STR.as_bytes(), $crate::concat!($needle).as_bytes());
+        STR
+    }};
+}
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that the result contains a subslice.
+///
+/// This is the byte slice equivalent of [`concat_require_contains!`], the
+/// operands are concatenated using [`concat_bytes!`] and the needle is a
+/// `const` [`&[u8]`][slice].
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_require_contains;
+/// #
+/// const MAGIC: &[u8] = &[0xCA, 0xFE];
+/// const BLOB: &[u8] = concat_bytes_require_contains!(MAGIC; &[0x00], MAGIC, &[0x01]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_require_contains {
+    ($needle:expr; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_require_contains(ARR, $crate::concat_bytes!($needle));
+        ARR
+    }};
+}
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that the result does not contain a subslice.
+///
+/// This is the byte slice equivalent of [`concat_forbid_contains!`], the
+/// operands are concatenated using [`concat_bytes!`] and the needle is a
+/// `const` [`&[u8]`][slice].
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_forbid_contains;
+/// #
+/// const NUL: &[u8] = &[0x00];
+/// const BLOB: &[u8] = concat_bytes_forbid_contains!(NUL; &[0x01, 0x02], &[0x03]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_forbid_contains {
+    ($needle:expr; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_forbid_contains(ARR, $crate::concat_bytes!($needle));
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _require_contains(haystack: &[u8], needle: &[u8]) {
+    if _find(haystack, needle).is_none() {
+        panic!("concatenation does not contain the required needle");
+    }
+}
+
+#[doc(hidden)]
+pub const fn _forbid_contains(haystack: &[u8], needle: &[u8]) {
+    if let Some(i) = _find(haystack, needle) {
+        _Msg::new()
+            .str("concatenation contains the forbidden needle at index ")
+            .usize(i)
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_with_offsets!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice along
+/// with the offset of each operand.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `(&'static [T], &'static [usize])`. The first element is
+/// the concatenation and the second element contains the starting offset of
+/// each operand within it, i.e. `[0, A.len(), A.len() + B.len(), ...]`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_with_offsets;
+/// #
+/// const A: &[u8] = b"one";
+/// const B: &[u8] = b"three";
+/// const C: &[u8] = b"!";
+/// const RECORDS: (&[u8], &[usize]) = concat_with_offsets!([u8]: A, B, C);
+/// assert_eq!(RECORDS, (b"onethree!".as_slice(), [0, 3, 8].as_slice()));
+/// ```
+#[macro_export]
+macro_rules! concat_with_offsets {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const LENS: &[usize] = &[$($crate::concat_slices!([$T]: $s).len()),*];
+        const OFFSETS: [usize; LENS.len()] = $crate::_start_offsets(LENS);
+        const TUPLE: (&[$T], &[usize]) = ($crate::concat_slices!([$T]: $($s),*), &OFFSETS);
+        TUPLE
+    }};
+}
+
+/// Returns the starting offset of each operand given their lengths.
+#[doc(hidden)]
+pub const fn _start_offsets<const N: usize>(lens: &[usize]) -> [usize; N] {
+    let mut offsets = [0; N];
+    let mut i = 1;
+    while i < N {
+        offsets[i] = offsets[i - 1] + lens[i - 1];
+        i += 1;
+    }
+    offsets
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_lf!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with normalized line endings.
+///
+/// This macro works exactly like [`concat!`] except that every `\r\n` and
+/// every lone `\r` in the result is replaced with `\n`. No other characters
+/// are normalized. Since this happens after concatenation a `\r` at the end of
+/// one operand followed by a `\n` at the start of the next is treated as a
+/// single line ending.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_lf;
+/// #
+/// const WINDOWS: &str = "line one\r\nline two\r\n";
+/// const MAC: &str = "line three\r";
+/// const TEXT: &str = concat_lf!(WINDOWS, MAC, "line four\n");
+/// assert_eq!(TEXT, "line one\nline two\nline three\nline four\n");
+/// ```
+#[macro_export]
+macro_rules! concat_lf {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_lf::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_lf::<LEN>(SRC).finish();
+        // SAFETY: Only ASCII characters are removed or replaced so the result
+        // is still valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _lf<const N: usize>(s: &str) -> _Buf<N> {
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\r' {
+            buf = buf.push(b'\n');
+            if i + 1 < bytes.len() && bytes[i + 1] == b'\n' {
+                i += 1;
+            }
+        } else {
+            buf = buf.push(bytes[i]);
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_c_array!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and format
+/// the result as a C array initializer.
+///
+/// This macro takes any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`] and yields an expression of type [`&'static str`][str]
+/// containing the concatenated bytes formatted like `{0x01, 0x02, 0x03}`
+/// which is suitable for pasting into C source code.
+///
+/// The output can be configured by passing options separated by commas and
+/// terminated by a semicolon before the operands.
+///
+/// - `radix = hex` formats each byte as `0x` followed by two lowercase
+///   hexadecimal digits. This is the default.
+/// - `radix = dec` formats each byte in decimal.
+/// - `sep = <&str>` sets the separator placed between bytes, by default this
+///   is `", "`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_c_array;
+/// #
+/// const TABLE: &[u8] = &[0x01, 0x02, 0xFF];
+///
+/// const HEX: &str = concat_c_array!(TABLE);
+/// assert_eq!(HEX, "{0x01, 0x02, 0xff}");
+///
+/// const DEC: &str = concat_c_array!(radix = dec, sep = ","; TABLE, &[16]);
+/// assert_eq!(DEC, "{1,2,255,16}");
+/// ```
+#[macro_export]
+macro_rules! concat_c_array {
+    (radix = $radix:ident, sep = $sep:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const RADIX: u8 = $crate::_c_array_radix!($radix);
+        const LEN: usize = $crate::_c_array::<0>(SRC, RADIX, $sep).len();
+        const ARR: [u8; LEN] = $crate::_c_array::<LEN>(SRC, RADIX, $sep).finish();
+        // SAFETY: The array only contains ASCII digits, punctuation, and the
+        // bytes of the separator string so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+    (sep = $sep:expr, radix = $radix:ident; $($e:expr),* $(,)?) => {
+        $crate::concat_c_array!(radix = $radix, sep = $sep; $($e),*)
+    };
+    (radix = $radix:ident; $($e:expr),* $(,)?) => {
+        $crate::concat_c_array!(radix = $radix, sep = ", "; $($e),*)
+    };
+    (sep = $sep:expr; $($e:expr),* $(,)?) => {
+        $crate::concat_c_array!(radix = hex, sep = $sep; $($e),*)
+    };
+    ($($e:expr),* $(,)?) => {
+        $crate::concat_c_array!(radix = hex, sep = ", "; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _c_array_radix {
+    (hex) => {
+        16
+    };
+    (dec) => {
+        10
+    };
+}
+
+#[doc(hidden)]
+pub const fn _c_array<const N: usize>(src: &[u8], radix: u8, sep: &str) -> _Buf<N> {
+    let mut buf = _Buf::new().push(b'{');
+    let mut i = 0;
+    while i < src.len() {
+        if i > 0 {
+            buf = buf.str(sep);
+        }
+        buf = match radix {
+            16 => buf.str("0x").hex_lower(src[i]),
+            _ => buf.u128(src[i] as u128),
+        };
+        i += 1;
+    }
+    buf.push(b'}')
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bounded!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices into a static slice and assert that
+/// every element lies within a range.
+///
+/// This macro requires the type of slice and the bounds to be specified in the
+/// form `[T]: min = <T>, max = <T>; ` before the comma separated expressions.
+/// Either bound may be omitted, e.g. `[T]: max = <T>; `. It works exactly like
+/// [`concat_slices!`] except that it fails to compile if any element is less
+/// than `min` or greater than `max`. Both bounds are inclusive. The panic
+/// message names the index and value of the first offending element.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bounded;
+/// #
+/// const LOW: &[u8] = &[0x00, 0x10];
+/// const HIGH: &[u8] = &[0x70, 0x7F];
+/// const SEVEN_BIT: &[u8] = concat_bounded!([u8]: max = 0x7F; LOW, HIGH);
+///
+/// const PERCENT: &[i32] = concat_bounded!([i32]: min = 0, max = 100; &[0, 50, 100]);
+/// ```
+///
+/// The following fails to compile because `0x80` is greater than `0x7F`.
+///
+/// ```compile_fail
+/// # use constcat::concat_bounded;
+/// #
+/// const SEVEN_BIT: &[u8] = concat_bounded!([u8]: max = 0x7F; &[0x00, 0x80]);
+/// ```
+#[macro_export]
+macro_rules! concat_bounded {
+    ([$T:ty]: min = $min:expr, max = $max:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_bounded!([$T]: $min, $max; $($s),*)
+    };
+    ([$T:ty]: min = $min:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_bounded!([$T]: $min, <$T>::MAX; $($s),*)
+    };
+    ([$T:ty]: max = $max:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_bounded!([$T]: <$T>::MIN, $max; $($s),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_bounded {
+    ([$T:ty]: $min:expr, $max:expr; $($s:expr),*) => {{
+        const ARR: &[$T] = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let min: $T = $min;
+            let max: $T = $max;
+            let mut i = 0;
+            while i < arr.len() {
+                if arr[i] < min || arr[i] > max {
+                    $crate::_Msg::new()
+                        .str("element at index ")
+                        .usize(i)
+                        .str(" is out of bounds: ")
+                        .int($crate::_int!(arr[i]))
+                        .panic();
+                }
+                i += 1;
+            }
+            arr
+        };
+        ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_range!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer ranges and slices into a static slice.
+///
+/// This macro requires the type of slice to be specified in the form `[T]: `
+/// before the comma separated operands. Each operand is either an exclusive
+/// range `a..b`, an inclusive range `a..=b`, or any [`&[T]`][slice]
+/// expression accepted by [`concat_slices!`]. It yields an expression of type
+/// [`&'static [T]`][slice] which is the result of all of the operands
+/// concatenated left-to-right, where each range contributes each of its
+/// elements in ascending order. Empty ranges contribute nothing.
+///
+/// The element type must be a primitive integer and the range bounds must be of
+/// type `T`, so a bound that is out of range for `T` fails to compile. Range
+/// bounds must each be a single token, i.e. a literal, an optionally negated
+/// literal, a constant, or a parenthesized expression.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_range;
+/// #
+/// const DIGITS: &[i32] = concat_range!([i32]: 0..5, 10..=12);
+/// assert_eq!(DIGITS, [0, 1, 2, 3, 4, 10, 11, 12]);
+///
+/// const END: u8 = 4;
+/// const MIXED: &[u8] = concat_range!([u8]: &[0xFF], 1..END, (END * 2)..=9);
+/// assert_eq!(MIXED, [0xFF, 1, 2, 3, 8, 9]);
+/// ```
+///
+/// The following fails to compile because the end bound is not a `u8`.
+///
+/// ```compile_fail
+/// # use constcat::concat_range;
+/// #
+/// const END: i32 = 300;
+/// const BYTES: &[u8] = concat_range!([u8]: 250..END);
+/// ```
+#[macro_export]
+macro_rules! concat_range {
+    ([$T:ty]: $($rest:tt)*) => {
+        $crate::_concat_range!([$T] [] $($rest)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_range {
+    ([$T:ty] [$($acc:expr),*] $(,)?) => {
+        $crate::concat_slices!([$T]: $($acc),*)
+    };
+
+    // Inclusive ranges
+    ([$T:ty] [$($acc:expr),*] $a:literal ..= $b:literal $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, true)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:literal ..= $b:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, true)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:tt ..= $b:literal $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, true)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:tt ..= $b:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, true)] $($($rest)*)?)
+    };
+
+    // Exclusive ranges
+    ([$T:ty] [$($acc:expr),*] $a:literal .. $b:literal $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, false)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:literal .. $b:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, false)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:tt .. $b:literal $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, false)] $($($rest)*)?)
+    };
+    ([$T:ty] [$($acc:expr),*] $a:tt .. $b:tt $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $crate::_range!([$T]: $a, $b, false)] $($($rest)*)?)
+    };
+
+    // Slices
+    ([$T:ty] [$($acc:expr),*] $s:expr $(, $($rest:tt)*)?) => {
+        $crate::_concat_range!([$T] [$($acc,)* $s] $($($rest)*)?)
+    };
+}
+
+/// Expands to a reference to an array containing the elements of the range
+/// from `$a` to `$b`, where `$inclusive` is `true` if `$b` is included.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _range {
+    ([$T:ty]: $a:expr, $b:expr, $inclusive:expr) => {{
+        const BOUNDS: ($T, $T) = ($a, $b);
+        const LEN: usize = if BOUNDS.0 < BOUNDS.1 {
+            BOUNDS.1.abs_diff(BOUNDS.0) as usize + $inclusive as usize
+        } else if BOUNDS.0 == BOUNDS.1 {
+            $inclusive as usize
+        } else {
+            0
+        };
+        const ARR: [$T; LEN] = {
+            let mut arr: [$T; LEN] = [0; LEN];
+            let mut value = BOUNDS.0;
+            let mut i = 0;
+            while i < LEN {
+                arr[i] = value;
+                i += 1;
+                // Only step while there are elements left so that the last
+                // value is never incremented past the end bound.
+                if i < LEN {
+                    value += 1;
+                }
+            }
+            arr
+        };
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_hexdump!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and format
+/// the result as a hexdump.
+///
+/// This macro takes any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`] and yields an expression of type [`&'static str`][str]
+/// containing a hexdump of the concatenated bytes. The number of bytes per
+/// line defaults to 16 and can be configured by passing `width = <usize>; `
+/// before the operands.
+///
+/// Each line consists of the offset of the first byte as eight lowercase
+/// hexadecimal digits, two spaces, each byte as two lowercase hexadecimal
+/// digits separated by spaces, two spaces, and then the bytes as ASCII between
+/// `|` characters. Bytes that are not printable ASCII are shown as `.`. The
+/// hexadecimal columns of the last line are padded with spaces so that the
+/// ASCII columns line up. Every line ends with a newline, and an empty input
+/// produces an empty string.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_hexdump;
+/// #
+/// const DUMP: &str = concat_hexdump!(width = 8; b"Hello World\n".as_slice());
+/// assert_eq!(
+///     DUMP,
+///     "00000000  48 65 6c 6c 6f 20 57 6f  |Hello Wo|\n\
+///      00000008  72 6c 64 0a              |rld.|\n"
+/// );
+/// ```
+#[macro_export]
+macro_rules! concat_hexdump {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_hexdump::<0>(SRC, $width).len();
+        const ARR: [u8; LEN] = $crate::_hexdump::<LEN>(SRC, $width).finish();
+        // SAFETY: The array only contains ASCII characters.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+    ($($e:expr),* $(,)?) => {
+        $crate::concat_hexdump!(width = 16; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+pub const fn _hexdump<const N: usize>(src: &[u8], width: usize) -> _Buf<N> {
+    if width == 0 {
+        panic!("`width` must be greater than zero");
+    }
+    let mut buf = _Buf::new();
+    let mut line = 0;
+    while line < src.len() {
+        let offset = line as u32;
+        buf = buf
+            .hex_lower((offset >> 24) as u8)
+            .hex_lower((offset >> 16) as u8)
+            .hex_lower((offset >> 8) as u8)
+            .hex_lower(offset as u8)
+            .str(" ");
+        let mut i = 0;
+        while i < width {
+            buf = buf.str(" ");
+            if line + i < src.len() {
+                buf = buf.hex_lower(src[line + i]);
+            } else {
+                buf = buf.str("  ");
+            }
+            i += 1;
+        }
+        buf = buf.str("  |");
+        let mut i = 0;
+        while i < width && line + i < src.len() {
+            let b = src[line + i];
+            buf = buf.push(if b.is_ascii_graphic() || b == b' ' {
+                b
+            } else {
+                b'.'
+            });
+            i += 1;
+        }
+        buf = buf.str("|\n");
+        line += width;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_slices_opt!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`Option<&[T]>`][Option] expressions into a static
+/// slice.
+///
+/// This macro works like [`concat_slices!`] except each operand is an
+/// `Option<&[T]>`. Operands that are `Some` contribute their elements and
+/// operands that are `None` contribute nothing. This makes it possible to
+/// conditionally include slices based on `const` logic.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_slices_opt;
+/// #
+/// const BASE: &[&str] = &["help", "version"];
+/// const DEBUG: Option<&[&str]> = if cfg!(debug_assertions) {
+///     Some(&["trace"])
+/// } else {
+///     None
+/// };
+/// const COMMANDS: &[&str] = concat_slices_opt!([&str]: Some(BASE), DEBUG, None);
+/// ```
+#[macro_export]
+macro_rules! concat_slices_opt {
+    ([$T:ty]: $($o:expr),* $(,)?) => {
+        $crate::concat_slices!([$T]: $({
+            let opt: Option<&[$T]> = $o;
+            match opt {
+                Some(s) => s,
+                None => &[],
+            }
+        }),*)
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_first! and concat_last!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the first
+/// element of the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the first element of the concatenation. The
+/// macro fails to compile if the concatenation is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_first;
+/// #
+/// const EMPTY: &[u8] = &[];
+/// const TABLE: &[u8] = &[3, 2, 1];
+/// const FIRST: u8 = concat_first!([u8]: EMPTY, TABLE);
+/// assert_eq!(FIRST, 3);
+/// ```
+#[macro_export]
+macro_rules! concat_first {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const FIRST: $T = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            match arr.first() {
+                Some(first) => *first,
+                None => panic!("cannot take the first element of an empty concatenation"),
+            }
+        };
+        FIRST
+    }};
+}
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the last
+/// element of the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the last element of the concatenation. The
+/// macro fails to compile if the concatenation is empty.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_last;
+/// #
+/// const EMPTY: &[u8] = &[];
+/// const TABLE: &[u8] = &[3, 2, 1];
+/// const LAST: u8 = concat_last!([u8]: TABLE, EMPTY);
+/// assert_eq!(LAST, 1);
+/// ```
+#[macro_export]
+macro_rules! concat_last {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const LAST: $T = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            match arr.last() {
+                Some(last) => *last,
+                None => panic!("cannot take the last element of an empty concatenation"),
+            }
+        };
+        LAST
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_rotate_left! and concat_rotate_right!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// rotate the result to the left.
+///
+/// This macro requires the type of slice and the rotation to be specified in
+/// the form `[T]: <usize>; ` before the comma separated expressions. It yields
+/// an expression of type [`&'static [T]`][slice] which is the result of all of
+/// the expressions concatenated left-to-right and then rotated left by `k`
+/// elements, i.e. the element at index `k` becomes the first element. This
+/// follows the semantics of [`slice::rotate_left`] except that `k` may be
+/// larger than the length, in which case it is reduced modulo the length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_rotate_left;
+/// #
+/// const A: &[u8] = &[1, 2, 3];
+/// const B: &[u8] = &[4, 5];
+/// const ROTATED: &[u8] = concat_rotate_left!([u8]: 2; A, B);
+/// assert_eq!(ROTATED, [3, 4, 5, 1, 2]);
+/// ```
+#[macro_export]
+macro_rules! concat_rotate_left {
+    ([$T:ty]: $k:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_rotate!([$T]: |len: usize| $k % len; $($s),*)
+    };
+}
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// rotate the result to the right.
+///
+/// This macro requires the type of slice and the rotation to be specified in
+/// the form `[T]: <usize>; ` before the comma separated expressions. It yields
+/// an expression of type [`&'static [T]`][slice] which is the result of all of
+/// the expressions concatenated left-to-right and then rotated right by `k`
+/// elements, i.e. the last `k` elements move to the front. This follows the
+/// semantics of [`slice::rotate_right`] except that `k` may be larger than the
+/// length, in which case it is reduced modulo the length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_rotate_right;
+/// #
+/// const A: &[u8] = &[1, 2, 3];
+/// const B: &[u8] = &[4, 5];
+/// const ROTATED: &[u8] = concat_rotate_right!([u8]: 2; A, B);
+/// assert_eq!(ROTATED, [4, 5, 1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! concat_rotate_right {
+    ([$T:ty]: $k:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_rotate!([$T]: |len: usize| len - $k % len; $($s),*)
+    };
+}
+
+/// Concatenates the slices and rotates the result left by the number of
+/// elements returned by `$mid` for the total length.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_rotate {
+    ([$T:ty]: |$len:ident: usize| $mid:expr; $($s:expr),*) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const LEN: usize = SRC.len();
+        const ARR: [$T; LEN] = {
+            let $len = LEN;
+            let mid = if LEN == 0 { 0 } else { $mid };
+            $crate::_from_fn!([$T; LEN], |i| SRC[(mid + i) % LEN])
+        };
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_char_str!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[char]`][slice] expressions into a static string
+/// slice.
+///
+/// This macro takes any number of comma-separated [`&[char]`][slice] or
+/// `[char; N]` expressions and yields an expression of type
+/// [`&'static str`][str] which is the UTF-8 encoding of all of the characters
+/// concatenated left-to-right.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_char_str;
+/// #
+/// const GREEK: &[char] = &['α', 'β', 'γ'];
+/// const TEXT: &str = concat_char_str!(GREEK, &[' ', '🎉']);
+/// assert_eq!(TEXT, "αβγ 🎉");
+/// ```
+#[macro_export]
+macro_rules! concat_char_str {
+    ($($s:expr),* $(,)?) => {{
+        const SRC: &[char] = $crate::concat_slices!([char]: $($s),*);
+        const LEN: usize = $crate::_char_str::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_char_str::<LEN>(SRC).finish();
+        // SAFETY: The array contains the UTF-8 encoding of valid `char`s.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _char_str<const N: usize>(src: &[char]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        buf = buf.char(src[i]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_check! and assert_palindrome!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Assert that a `const` expression satisfies a predicate and return it.
+///
+/// This macro takes a constant expression, typically the output of one of the
+/// concatenation macros, and a path to a `const fn` that takes the value, e.g.
+/// a `const fn(&[T]) -> bool`. It fails to compile if the predicate returns
+/// `false`, otherwise it yields the expression unchanged. This makes it
+/// possible to validate any property of assembled data at compile time.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_check, concat_slices};
+/// #
+/// const fn is_even_length(s: &[u8]) -> bool {
+///     s.len() % 2 == 0
+/// }
+///
+/// const A: &[u8] = &[1, 2, 3];
+/// const PAIRS: &[u8] = concat_check!(concat_slices!([u8]: A, &[4]), is_even_length);
+/// ```
+///
+/// The following fails to compile because the result has an odd length.
+///
+/// ```compile_fail
+/// # use constcat::{concat_check, concat_slices};
+/// #
+/// # const fn is_even_length(s: &[u8]) -> bool {
+/// #     s.len() % 2 == 0
+/// # }
+/// const PAIRS: &[u8] = concat_check!(concat_slices!([u8]: &[1, 2, 3]), is_even_length);
+/// ```
+#[macro_export]
+macro_rules! concat_check {
+    ($e:expr, $f:path $(,)?) => {{
+        const _: () = {
+            if !$f($e) {
+                panic!("concatenation does not satisfy the predicate");
+            }
+        };
+        $e
+    }};
+}
+
+/// Assert that a `const` slice expression is a palindrome.
+///
+/// This macro takes a constant slice expression, typically the output of one
+/// of the concatenation macros, and fails to compile if it does not read the
+/// same forwards and backwards. The panic message names the index of the
+/// first element that does not match its mirror. The element type must
+/// support the `!=` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// This macro can be used anywhere an item or statement is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{assert_palindrome, concat_bytes};
+/// #
+/// const HALF: &[u8] = &[1, 2, 3];
+/// const MIRROR: &[u8] = &[3, 2, 1];
+/// assert_palindrome!(concat_bytes!(HALF, MIRROR));
+/// ```
+#[macro_export]
+macro_rules! assert_palindrome {
+    ($e:expr $(,)?) => {
+        const _: () = {
+            let s = $e;
+            let mut i = 0;
+            while i < s.len() / 2 {
+                if s[i] != s[s.len() - 1 - i] {
+                    $crate::_Msg::new()
+                        .str("not a palindrome, element at index ")
+                        .usize(i)
+                        .str(" does not match its mirror")
+                        .panic();
+                }
+                i += 1;
+            }
+        };
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_min! and concat_max!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the smallest
+/// element of the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the smallest element of the concatenation.
+/// If several elements are equally small the first one is returned. The macro
+/// fails to compile if the concatenation is empty.
+///
+/// The element type must support the `<` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_min;
+/// #
+/// const A: &[i32] = &[5, -3, 8];
+/// const B: &[i32] = &[0, 12];
+/// const MIN: i32 = concat_min!([i32]: A, B);
+/// assert_eq!(MIN, -3);
+/// ```
+#[macro_export]
+macro_rules! concat_min {
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::_concat_reduce!([$T]: |a, b| b < a, "minimum"; $($s),*)
+    };
+}
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the largest
+/// element of the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the largest element of the concatenation.
+/// If several elements are equally large the first one is returned. The macro
+/// fails to compile if the concatenation is empty.
+///
+/// The element type must support the `>` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_max;
+/// #
+/// const A: &[i32] = &[5, -3, 8];
+/// const B: &[i32] = &[0, 12];
+/// const MAX: i32 = concat_max!([i32]: A, B);
+/// assert_eq!(MAX, 12);
+/// ```
+#[macro_export]
+macro_rules! concat_max {
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::_concat_reduce!([$T]: |a, b| b > a, "maximum"; $($s),*)
+    };
+}
+
+/// Concatenates the slices and returns the first element for which no later
+/// element `b` satisfies `$better` when compared to the current best `a`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_reduce {
+    ([$T:ty]: |$a:ident, $b:ident| $better:expr, $what:literal; $($s:expr),*) => {{
+        const BEST: $T = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            if arr.is_empty() {
+                panic!($crate::core::concat!(
+                    "cannot take the ",
+                    $what,
+                    " of an empty concatenation"
+                ));
+            }
+            let mut best = arr[0];
+            let mut i = 1;
+            while i < arr.len() {
+                let $a = best;
+                let $b = arr[i];
+                if $better {
+                    best = $b;
+                }
+                i += 1;
+            }
+            best
+        };
+        BEST
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_encode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// byte slice using a specific character encoding.
+///
+/// This macro takes an encoding in the form `encoding = <name>; ` followed by
+/// any number of comma-separated expressions as accepted by [`concat!`]. It
+/// yields an expression of type [`&'static [u8]`][slice] which is the result
+/// of all of the expressions concatenated left-to-right and then encoded. The
+/// macro fails to compile if the result contains a character that cannot be
+/// represented in the encoding. The following encodings are supported.
+///
+/// - `utf8`: the bytes of the string as is, the same as `concat!(..).as_bytes()`.
+/// - `ascii`: one byte per character, only characters up to `U+007F`.
+/// - `latin1`: ISO-8859-1, one byte per character, only characters up to
+///   `U+00FF`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_encode;
+/// #
+/// const NAME: &str = "Café";
+/// const LATIN1: &[u8] = concat_encode!(encoding = latin1; NAME, " ©");
+/// assert_eq!(LATIN1, b"Caf\xE9 \xA9");
+/// ```
+///
+/// The following fails to compile because `é` is not ASCII.
+///
+/// ```compile_fail
+/// # use constcat::concat_encode;
+/// #
+/// const ASCII: &[u8] = concat_encode!(encoding = ascii; "Café");
+/// ```
+#[macro_export]
+macro_rules! concat_encode {
+    (encoding = utf8; $($e:expr),* $(,)?) => {
+        $crate::concat!($($e),*).as_bytes()
+    };
+    (encoding = ascii; $($e:expr),* $(,)?) => {
+        $crate::_concat_encode!(0x7F, "ASCII"; $($e),*)
+    };
+    (encoding = latin1; $($e:expr),* $(,)?) => {
+        $crate::_concat_encode!(0xFF, "Latin-1"; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_encode {
+    ($max:literal, $name:literal; $($e:expr),*) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_encode_single_byte::<0>(SRC, $max, $name).len();
+        const ARR: [u8; LEN] = $crate::_encode_single_byte::<LEN>(SRC, $max, $name).finish();
+        &ARR
+    }};
+}
+
+/// Encodes each character as a single byte, panicking on any character above
+/// `max`.
+#[doc(hidden)]
+pub const fn _encode_single_byte<const N: usize>(s: &str, max: u32, name: &str) -> _Buf<N> {
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let (c, n) = _decode_utf8(bytes, i);
+        if c > max {
+            _Msg::new()
+                .str("character at byte index ")
+                .usize(i)
+                .str(" cannot be encoded as ")
+                .str(name)
+                .panic();
+        }
+        buf = buf.push(c as u8);
+        i += n;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_grid!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static
+/// two-dimensional array.
+///
+/// This macro requires the type of slice and the number of columns to be
+/// specified in the form `[T]: cols = <usize>; ` before the comma separated
+/// expressions. It yields an expression of type `&'static [[T; C]; ROWS]`
+/// where each row contains the next `C` elements of the concatenation and
+/// `ROWS` is inferred from the total length. The macro fails to compile if the
+/// total length is not a multiple of `C`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_grid;
+/// #
+/// const IDENTITY: &[[i32; 3]; 3] = concat_grid!([i32]: cols = 3; &[1, 0, 0], &[0, 1, 0, 0, 0, 1]);
+/// assert_eq!(IDENTITY, &[[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+/// ```
+#[macro_export]
+macro_rules! concat_grid {
+    ([$T:ty]: cols = $cols:expr; $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const ROWS: usize = $crate::_chunk_count(SRC.len(), $cols);
+        const ARR: [[$T; $cols]; ROWS] = $crate::_from_fn!([[$T; $cols]; ROWS], |row| {
+            $crate::_from_fn!([$T; $cols], |col| SRC[row * $cols + col])
+        });
+        &ARR
+    }};
+}
+
+/// Returns the number of chunks of the given size, panicking if the length is
+/// not an exact multiple of it.
+#[doc(hidden)]
+pub const fn _chunk_count(len: usize, size: usize) -> usize {
+    if size == 0 {
+        panic!("chunk size must be greater than zero");
+    }
+    if len % size != 0 {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(len)
+            .str(" is not a multiple of ")
+            .usize(size)
+            .panic();
+    }
+    len / size
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_base32!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and encode
+/// the result as base32.
+///
+/// This macro takes any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`] and yields an expression of type [`&'static str`][str]
+/// which is the [RFC 4648] base32 encoding of the concatenated bytes, using
+/// the standard `A-Z2-7` alphabet. The output is padded with `=` to a multiple
+/// of eight characters, pass `pad = false; ` before the operands to omit the
+/// padding.
+///
+/// [RFC 4648]: https://www.rfc-editor.org/rfc/rfc4648#section-6
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_base32;
+/// #
+/// const SECRET: &[u8] = b"foo";
+/// const PADDED: &str = concat_base32!(SECRET, b"bar".as_slice());
+/// assert_eq!(PADDED, "MZXW6YTBOI======");
+///
+/// const UNPADDED: &str = concat_base32!(pad = false; SECRET);
+/// assert_eq!(UNPADDED, "MZXW6");
+/// ```
+#[macro_export]
+macro_rules! concat_base32 {
+    (pad = $pad:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_base32::<0>(SRC, $pad).len();
+        const ARR: [u8; LEN] = $crate::_base32::<LEN>(SRC, $pad).finish();
+        // SAFETY: The base32 alphabet and padding character are ASCII.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+    ($($e:expr),* $(,)?) => {
+        $crate::concat_base32!(pad = true; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+pub const fn _base32<const N: usize>(src: &[u8], pad: bool) -> _Buf<N> {
+    const ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+    let mut buf = _Buf::new();
+    let mut acc: u16 = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    while i < src.len() {
+        acc = (acc << 8) | src[i] as u16;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            buf = buf.push(ALPHABET[((acc >> bits) & 0x1F) as usize]);
+        }
+        i += 1;
+    }
+    if bits > 0 {
+        buf = buf.push(ALPHABET[((acc << (5 - bits)) & 0x1F) as usize]);
+    }
+    while pad && buf.len() % 8 != 0 {
+        buf = buf.push(b'=');
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_debug!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice along with a description of the layout.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type `(&'static [u8], &'static str)`. The first element is
+/// the concatenation and the second element describes the layout of the
+/// operands within it.
+///
+/// The description is only generated when the `debug_layout` feature is
+/// enabled, otherwise it is always the empty string and no extra data is
+/// included in the binary. Each line of the description has the source of the
+/// operand, its byte range, and its length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_debug;
+/// #
+/// const MAGIC: &[u8] = &[0x7F, 0x45, 0x4C, 0x46];
+/// const VERSION: &[u8] = &[1];
+/// const HEADER: (&[u8], &str) = concat_bytes_debug!(MAGIC, VERSION);
+/// assert_eq!(HEADER.0, [0x7F, 0x45, 0x4C, 0x46, 0x01]);
+/// if cfg!(feature = "debug_layout") {
+///     assert_eq!(HEADER.1, "MAGIC @ 0..4 (4 bytes)\nVERSION @ 4..5 (1 byte)");
+/// }
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_debug {
+    ($($e:expr),* $(,)?) => {{
+        const NAMES: &[&str] = &[$($crate::core::stringify!($e)),*];
+        const LENS: &[usize] = &[$($crate::concat_bytes!($e).len()),*];
+        const LEN: usize = $crate::_layout::<0>(NAMES, LENS).len();
+        const ARR: [u8; LEN] = $crate::_layout::<LEN>(NAMES, LENS).finish();
+        const TUPLE: (&[u8], &str) = (
+            $crate::concat_bytes!($($e),*),
+            // SAFETY: The array only contains ASCII and the bytes of the
+            // operand source strings so it is valid UTF-8.
+            unsafe { $crate::core::str::from_utf8_unchecked(&ARR) },
+        );
+        TUPLE
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _layout<const N: usize>(names: &[&str], lens: &[usize]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    if !cfg!(feature = "debug_layout") {
+        return buf;
+    }
+    let mut offset = 0;
+    let mut i = 0;
+    while i < names.len() {
+        if i > 0 {
+            buf = buf.push(b'\n');
+        }
+        buf = buf
+            .str(names[i])
+            .str(" @ ")
+            .usize(offset)
+            .str("..")
+            .usize(offset + lens[i])
+            .str(" (")
+            .usize(lens[i])
+            .str(if lens[i] == 1 { " byte)" } else { " bytes)" });
+        offset += lens[i];
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_aligned_for!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that the length fits a whole number of `T`s.
+///
+/// This macro takes a type followed by a semicolon and then any number of
+/// comma-separated expressions as accepted by [`concat_bytes!`]. It works
+/// exactly like [`concat_bytes!`] except that it fails to compile unless the
+/// total length is a multiple of both `align_of::<T>()` and `size_of::<T>()`.
+/// This enforces the length precondition for later reinterpreting the bytes as
+/// a `&[T]`.
+///
+/// Note that this only checks the length, the address of the resulting byte
+/// slice is not guaranteed to be aligned for `T`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_aligned_for;
+/// #
+/// const A: &[u8] = &1u32.to_le_bytes();
+/// const B: &[u8] = &2u32.to_le_bytes();
+/// const WORDS: &[u8] = concat_bytes_aligned_for!(u32; A, B);
+/// ```
+///
+/// The following fails to compile because 6 bytes is not a multiple of 4.
+///
+/// ```compile_fail
+/// # use constcat::concat_bytes_aligned_for;
+/// #
+/// const WORDS: &[u8] = concat_bytes_aligned_for!(u32; &[1, 2, 3, 4], &[5, 6]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_aligned_for {
+    ($T:ty; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_aligned_for(
+            ARR.len(),
+            $crate::core::mem::align_of::<$T>(),
+            $crate::core::mem::size_of::<$T>(),
+        );
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _aligned_for(len: usize, align: usize, size: usize) {
+    if len % align != 0 {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(len)
+            .str(" is not a multiple of the alignment ")
+            .usize(align)
+            .panic();
+    }
+    if size != 0 && len % size != 0 {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(len)
+            .str(" is not a multiple of the size ")
+            .usize(size)
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_sha256!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice along with its SHA-256 digest.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type `(&'static [u8], &'static str)`. The first element is
+/// the concatenation and the second element is the SHA-256 digest of it as 64
+/// lowercase hexadecimal digits. The digest is computed at compile time.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_sha256;
+/// #
+/// const A: &[u8] = &[b'a', b'b'];
+/// const B: &[u8] = &[b'c'];
+/// const HASHED: (&[u8], &str) = concat_bytes_sha256!(A, B);
+/// assert_eq!(HASHED.0, [b'a', b'b', b'c']);
+/// assert_eq!(
+///     HASHED.1,
+///     "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+/// );
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_sha256 {
+    ($($e:expr),* $(,)?) => {{
+        const BYTES: &[u8] = $crate::concat_bytes!($($e),*);
+        const ARR: [u8; 64] = $crate::_sha256_hex(BYTES).finish();
+        const TUPLE: (&[u8], &str) = (
+            BYTES,
+            // SAFETY: The array only contains ASCII hexadecimal digits.
+            unsafe { $crate::core::str::from_utf8_unchecked(&ARR) },
+        );
+        TUPLE
+    }};
+}
+
+const SHA256_K: [u32; 64] = [
+    0x428a2f98, 0x71374491, 0xb5c0fbcf, 0xe9b5dba5, 0x3956c25b, 0x59f111f1, 0x923f82a4, 0xab1c5ed5,
+    0xd807aa98, 0x12835b01, 0x243185be, 0x550c7dc3, 0x72be5d74, 0x80deb1fe, 0x9bdc06a7, 0xc19bf174,
+    0xe49b69c1, 0xefbe4786, 0x0fc19dc6, 0x240ca1cc, 0x2de92c6f, 0x4a7484aa, 0x5cb0a9dc, 0x76f988da,
+    0x983e5152, 0xa831c66d, 0xb00327c8, 0xbf597fc7, 0xc6e00bf3, 0xd5a79147, 0x06ca6351, 0x14292967,
+    0x27b70a85, 0x2e1b2138, 0x4d2c6dfc, 0x53380d13, 0x650a7354, 0x766a0abb, 0x81c2c92e, 0x92722c85,
+    0xa2bfe8a1, 0xa81a664b, 0xc24b8b70, 0xc76c51a3, 0xd192e819, 0xd6990624, 0xf40e3585, 0x106aa070,
+    0x19a4c116, 0x1e376c08, 0x2748774c, 0x34b0bcb5, 0x391c0cb3, 0x4ed8aa4a, 0x5b9cca4f, 0x682e6ff3,
+    0x748f82ee, 0x78a5636f, 0x84c87814, 0x8cc70208, 0x90befffa, 0xa4506ceb, 0xbef9a3f7, 0xc67178f2,
+];
+
+/// Returns the byte at `i` of the padded SHA-256 message.
+const fn _sha256_padded(src: &[u8], padded_len: usize, i: usize) -> u8 {
+    if i < src.len() {
+        src[i]
+    } else if i == src.len() {
+        0x80
+    } else if i >= padded_len - 8 {
+        let bits = (src.len() as u64).wrapping_mul(8);
+        (bits >> (8 * (padded_len - 1 - i))) as u8
+    } else {
+        0
+    }
+}
+
+#[doc(hidden)]
+pub const fn _sha256_hex(src: &[u8]) -> _Buf<64> {
+    let mut h: [u32; 8] = [
+        0x6a09e667, 0xbb67ae85, 0x3c6ef372, 0xa54ff53a, 0x510e527f, 0x9b05688c, 0x1f83d9ab,
+        0x5be0cd19,
+    ];
+    // One byte for the `0x80` terminator and eight for the bit length.
+    let padded_len = (src.len() + 9).div_ceil(64) * 64;
+    let mut block = 0;
+    while block < padded_len {
+        let mut w = [0u32; 64];
+        let mut t = 0;
+        while t < 16 {
+            let mut j = 0;
+            while j < 4 {
+                let byte = _sha256_padded(src, padded_len, block + 4 * t + j);
+                w[t] = (w[t] << 8) | byte as u32;
+                j += 1;
+            }
+            t += 1;
+        }
+        while t < 64 {
+            let s0 = w[t - 15].rotate_right(7) ^ w[t - 15].rotate_right(18) ^ (w[t - 15] >> 3);
+            let s1 = w[t - 2].rotate_right(17) ^ w[t - 2].rotate_right(19) ^ (w[t - 2] >> 10);
+            w[t] = w[t - 16]
+                .wrapping_add(s0)
+                .wrapping_add(w[t - 7])
+                .wrapping_add(s1);
+            t += 1;
+        }
+
+        let mut v = h;
+        t = 0;
+        while t < 64 {
+            let s1 = v[4].rotate_right(6) ^ v[4].rotate_right(11) ^ v[4].rotate_right(25);
+            let ch = (v[4] & v[5]) ^ (!v[4] & v[6]);
+            let temp1 = v[7]
+                .wrapping_add(s1)
+                .wrapping_add(ch)
+                .wrapping_add(SHA256_K[t])
+                .wrapping_add(w[t]);
+            let s0 = v[0].rotate_right(2) ^ v[0].rotate_right(13) ^ v[0].rotate_right(22);
+            let maj = (v[0] & v[1]) ^ (v[0] & v[2]) ^ (v[1] & v[2]);
+            let temp2 = s0.wrapping_add(maj);
+            v[7] = v[6];
+            v[6] = v[5];
+            v[5] = v[4];
+            v[4] = v[3].wrapping_add(temp1);
+            v[3] = v[2];
+            v[2] = v[1];
+            v[1] = v[0];
+            v[0] = temp1.wrapping_add(temp2);
+            t += 1;
+        }
+
+        let mut i = 0;
+        while i < 8 {
+            h[i] = h[i].wrapping_add(v[i]);
+            i += 1;
+        }
+        block += 64;
+    }
+
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < 8 {
+        let mut j = 0;
+        while j < 4 {
+            buf = buf.hex_lower((h[i] >> (24 - 8 * j)) as u8);
+            j += 1;
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_strip_bom! and concat_no_bom!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice, stripping a leading byte order mark from each operand.
+///
+/// This macro works exactly like [`concat!`] except that if an operand starts
+/// with a UTF-8 byte order mark (`U+FEFF`) it is removed before concatenating.
+/// Only a single leading byte order mark is removed from each operand.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_strip_bom;
+/// #
+/// const HEADER: &str = "\u{FEFF}name,value\n";
+/// const ROWS: &str = "\u{FEFF}a,1\n";
+/// const CSV: &str = concat_strip_bom!(HEADER, ROWS);
+/// assert_eq!(CSV, "name,value\na,1\n");
+/// ```
+#[macro_export]
+macro_rules! concat_strip_bom {
+    ($($e:expr),* $(,)?) => {
+        $crate::concat!($($crate::_strip_bom($crate::_maybe_std_concat!($e))),*)
+    };
+}
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that no interior operand starts with a byte order
+/// mark.
+///
+/// This macro works exactly like [`concat!`] except that it fails to compile
+/// if any operand other than the first starts with a UTF-8 byte order mark
+/// (`U+FEFF`). The panic message names the index of the offending operand.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_no_bom;
+/// #
+/// const HEADER: &str = "\u{FEFF}name,value\n";
+/// const ROWS: &str = "a,1\n";
+/// const CSV: &str = concat_no_bom!(HEADER, ROWS);
+/// ```
+///
+/// The following fails to compile because the second operand starts with a
+/// byte order mark.
+///
+/// ```compile_fail
+/// # use constcat::concat_no_bom;
+/// #
+/// const CSV: &str = concat_no_bom!("name,value\n", "\u{FEFF}a,1\n");
+/// ```
+#[macro_export]
+macro_rules! concat_no_bom {
+    ($($e:expr),* $(,)?) => {{
+        const _: () = $crate::_no_bom(&[$($crate::_maybe_std_concat!($e)),*]);
+        $crate::concat!($($e),*)
+    }};
+}
+
+const BOM: &[u8] = "\u{FEFF}".as_bytes();
+
+#[doc(hidden)]
+pub const fn _strip_bom(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    if !_starts_with_at(bytes, 0, BOM) {
+        return s;
+    }
+    // SAFETY: The byte order mark is a whole character so the remaining bytes
+    // are still valid UTF-8.
+    unsafe { core::str::from_utf8_unchecked(_subslice(bytes, BOM.len(), bytes.len())) }
+}
+
+#[doc(hidden)]
+pub const fn _no_bom(parts: &[&str]) {
+    let mut i = 1;
+    while i < parts.len() {
+        if _starts_with_at(parts[i].as_bytes(), 0, BOM) {
+            _Msg::new()
+                .str("byte order mark at the start of operand ")
+                .usize(i)
+                .panic();
+        }
+        i += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_flatten_options!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[Option<T>]`][slice] expressions into a static slice
+/// of the `Some` values.
+///
+/// This macro takes the element type `T` followed by any number of
+/// comma-separated [`&[Option<T>]`][slice] or `[Option<T>; N]` expressions and
+/// yields an expression of type [`&'static [T]`][slice]. The operands are
+/// concatenated left-to-right, every `None` element is dropped and every
+/// `Some(x)` element is replaced by `x`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_flatten_options;
+/// #
+/// const A: &[Option<u8>] = &[Some(1), None, Some(2)];
+/// const B: &[Option<u8>] = &[None, Some(3)];
+/// const SPARSE: &[u8] = concat_flatten_options!([u8]: A, B);
+/// assert_eq!(SPARSE, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! concat_flatten_options {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[Option<$T>] = $crate::concat_slices!([Option<$T>]: $($s),*);
+        const LEN: usize = $crate::_some_count(SRC);
+        const IDX: [usize; LEN] = $crate::_some_indices(SRC);
+        const ARR: [$T; LEN] = $crate::_from_fn!([$T; LEN], |i| match SRC[IDX[i]] {
+            Some(x) => x,
+            None => panic!("invalid index"),
+        });
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _some_count<T>(src: &[Option<T>]) -> usize {
+    let mut count = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if src[i].is_some() {
+            count += 1;
+        }
+        i += 1;
+    }
+    count
+}
+
+#[doc(hidden)]
+pub const fn _some_indices<T, const N: usize>(src: &[Option<T>]) -> [usize; N] {
+    let mut idx = [0; N];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if src[i].is_some() {
+            idx[n] = i;
+            n += 1;
+        }
+        i += 1;
+    }
+    idx
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_spanned!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice along with the byte range of each operand.
+///
+/// This macro takes the same input as [`concat!`] and yields an expression of
+/// type `(&'static str, &'static [(usize, usize)])`. The first element is the
+/// concatenation and the second element contains the `(start, end)` byte range
+/// of each operand within it. This can be used to map a position in the result
+/// back to the operand that it came from.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_spanned;
+/// #
+/// const GREETING: &str = "Hello";
+/// const SPANNED: (&str, &[(usize, usize)]) = concat_spanned!(GREETING, ", ", "world!");
+/// assert_eq!(SPANNED.0, "Hello, world!");
+/// assert_eq!(SPANNED.1, [(0, 5), (5, 7), (7, 13)]);
+/// ```
+#[macro_export]
+macro_rules! concat_spanned {
+    ($($e:expr),* $(,)?) => {{
+        const LENS: &[usize] = &[$($crate::concat!($e).len()),*];
+        const SPANS: [(usize, usize); LENS.len()] = $crate::_spans(LENS);
+        const TUPLE: (&str, &[(usize, usize)]) = ($crate::concat!($($e),*), &SPANS);
+        TUPLE
+    }};
+}
+
+/// Returns the `(start, end)` range of each operand given their lengths.
+#[doc(hidden)]
+pub const fn _spans<const N: usize>(lens: &[usize]) -> [(usize, usize); N] {
+    let mut spans = [(0, 0); N];
+    let mut offset = 0;
+    let mut i = 0;
+    while i < N {
+        spans[i] = (offset, offset + lens[i]);
+        offset += lens[i];
+        i += 1;
+    }
+    spans
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_urlencode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// percent-encoded string slice.
+///
+/// This macro works exactly like [`concat!`] except that every byte of the
+/// result outside of the [RFC 3986] unreserved set is percent-encoded as `%XX`
+/// using uppercase hexadecimal digits. The unreserved set is the ASCII letters
+/// and digits along with `-`, `.`, `_`, and `~`. Non-ASCII characters are
+/// encoded byte by byte from their UTF-8 representation.
+///
+/// [RFC 3986]: https://www.rfc-editor.org/rfc/rfc3986#section-2.3
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_urlencode;
+/// #
+/// const QUERY: &str = "name=Jürgen & co";
+/// const ENCODED: &str = concat_urlencode!(QUERY, "/v1.0");
+/// assert_eq!(ENCODED, "name%3DJ%C3%BCrgen%20%26%20co%2Fv1.0");
+/// ```
+#[macro_export]
+macro_rules! concat_urlencode {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_urlencode::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_urlencode::<LEN>(SRC).finish();
+        // SAFETY: The array only contains ASCII characters.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _urlencode<const N: usize>(s: &str) -> _Buf<N> {
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b.is_ascii_alphanumeric() || matches!(b, b'-' | b'.' | b'_' | b'~') {
+            buf = buf.push(b);
+        } else {
+            buf = buf.push(b'%').hex_upper(b);
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_html_escape!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// HTML-escaped string slice.
+///
+/// This macro works exactly like [`concat!`] except that the following
+/// characters in the result are replaced with HTML entities. No other
+/// characters are escaped.
+///
+/// | Character | Entity   |
+/// |-----------|----------|
+/// | `&`       | `&amp;`  |
+/// | `<`       | `&lt;`   |
+/// | `>`       | `&gt;`   |
+/// | `"`       | `&quot;` |
+/// | `'`       | `&#39;`  |
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_html_escape;
+/// #
+/// const TITLE: &str = "Tom & Jerry's <\"Show\">";
+/// const ESCAPED: &str = concat_html_escape!("Now playing: ", TITLE);
+/// assert_eq!(
+///     ESCAPED,
+///     "Now playing: Tom &amp; Jerry&#39;s &lt;&quot;Show&quot;&gt;"
+/// );
+/// ```
+#[macro_export]
+macro_rules! concat_html_escape {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_html_escape::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_html_escape::<LEN>(SRC).finish();
+        // SAFETY: Only ASCII characters are replaced, and only with ASCII
+        // characters, so the result is still valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _html_escape<const N: usize>(s: &str) -> _Buf<N> {
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        buf = match bytes[i] {
+            b'&' => buf.str("&amp;"),
+            b'<' => buf.str("&lt;"),
+            b'>' => buf.str("&gt;"),
+            b'"' => buf.str("&quot;"),
+            b'\'' => buf.str("&#39;"),
+            b => buf.push(b),
+        };
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_distinct!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// assert that no two operands are identical.
+///
+/// This macro works exactly like [`concat_slices!`] except that it fails to
+/// compile if any two operands have the same length and equal elements. This
+/// catches the same constant accidentally being passed twice in a long list of
+/// operands. Empty operands are never considered duplicates. The panic message
+/// names the indices of the first pair of duplicate operands.
+///
+/// The element type must support the `!=` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_distinct;
+/// #
+/// const RED: &[u8] = &[0xFF, 0x00, 0x00];
+/// const GREEN: &[u8] = &[0x00, 0xFF, 0x00];
+/// const BLUE: &[u8] = &[0x00, 0x00, 0xFF];
+/// const PALETTE: &[u8] = concat_distinct!([u8]: RED, GREEN, BLUE);
+/// ```
+///
+/// The following fails to compile because `GREEN` is passed twice.
+///
+/// ```compile_fail
+/// # use constcat::concat_distinct;
+/// #
+/// const RED: &[u8] = &[0xFF, 0x00, 0x00];
+/// const GREEN: &[u8] = &[0x00, 0xFF, 0x00];
+/// const PALETTE: &[u8] = concat_distinct!([u8]: RED, GREEN, GREEN);
+/// ```
+#[macro_export]
+macro_rules! concat_distinct {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const ARR: &[$T] = {
+            let ops: &[&[$T]] = &[$($crate::concat_slices!([$T]: $s)),*];
+            let mut i = 0;
+            while i < ops.len() {
+                let mut j = i + 1;
+                while j < ops.len() {
+                    let (a, b) = (ops[i], ops[j]);
+                    let mut eq = !a.is_empty() && a.len() == b.len();
+                    let mut k = 0;
+                    while eq && k < a.len() {
+                        eq = !(a[k] != b[k]);
+                        k += 1;
+                    }
+                    if eq {
+                        $crate::_Msg::new()
+                            .str("operands at indices ")
+                            .usize(i)
+                            .str(" and ")
+                            .usize(j)
+                            .str(" are identical")
+                            .panic();
+                    }
+                    j += 1;
+                }
+                i += 1;
+            }
+            $crate::concat_slices!([$T]: $($s),*)
+        };
+        ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_chunks!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice of
+/// fixed size chunks.
+///
+/// This macro requires the type of slice and the chunk size to be specified
+/// in the form `[T]: size = <usize>; ` before the comma separated expressions.
+/// It yields an expression of type `&'static [[T; S]]` where each chunk
+/// contains the next `S` elements of the concatenation. This is the same as
+/// [`concat_grid!`] except that the number of chunks is not part of the type.
+/// The macro fails to compile if the total length is not a multiple of `S`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_chunks;
+/// #
+/// const HEAD: &[u32] = &[0x1000, 64];
+/// const TAIL: &[u32] = &[0x2000, 128, 0x3000, 32];
+/// const DESCRIPTORS: &[[u32; 2]] = concat_chunks!([u32]: size = 2; HEAD, TAIL);
+/// assert_eq!(DESCRIPTORS, [[0x1000, 64], [0x2000, 128], [0x3000, 32]]);
+/// ```
+#[macro_export]
+macro_rules! concat_chunks {
+    ([$T:ty]: size = $size:expr; $($s:expr),* $(,)?) => {{
+        const CHUNKS: &[[$T; $size]] = $crate::concat_grid!([$T]: cols = $size; $($s),*);
+        CHUNKS
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_adler32!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice with an Adler-32 checksum appended.
+///
+/// This macro works exactly like [`concat_bytes!`] except that the [Adler-32]
+/// checksum of the concatenation is appended to it as four big-endian bytes,
+/// as in the zlib format. The checksum is computed at compile time.
+///
+/// [Adler-32]: https://www.rfc-editor.org/rfc/rfc1950#section-8
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_adler32;
+/// #
+/// const WIKI: &[u8] = &[b'W', b'i', b'k', b'i'];
+/// const PEDIA: &[u8] = &[b'p', b'e', b'd', b'i', b'a'];
+/// const BLOB: &[u8] = concat_bytes_adler32!(WIKI, PEDIA);
+/// assert_eq!(BLOB, b"Wikipedia\x11\xE6\x03\x98");
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_adler32 {
+    ($($e:expr),* $(,)?) => {{
+        const BYTES: &[u8] = $crate::concat_bytes!($($e),*);
+        const SUM: [u8; 4] = $crate::_adler32(BYTES).to_be_bytes();
+        $crate::concat_slices!([u8]: BYTES, SUM)
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _adler32(src: &[u8]) -> u32 {
+    const MOD: u32 = 65521;
+    let mut a: u32 = 1;
+    let mut b: u32 = 0;
+    let mut i = 0;
+    while i < src.len() {
+        a = (a + src[i] as u32) % MOD;
+        b = (b + a) % MOD;
+        i += 1;
+    }
+    (b << 16) | a
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_ident_str!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that the result is a valid ASCII identifier.
+///
+/// This macro works exactly like [`concat!`] except that it fails to compile
+/// unless the result is non-empty, starts with an ASCII letter or `_`, and
+/// only contains ASCII letters, digits and `_`. The panic message names the
+/// byte index of the first invalid character. Note that keywords are not
+/// rejected.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_ident_str;
+/// #
+/// const PREFIX: &str = "get_";
+/// const NAME: &str = concat_ident_str!(PREFIX, "value", 2);
+/// assert_eq!(NAME, "get_value2");
+/// ```
+///
+/// The following fails to compile because `-` is not allowed in identifiers.
+///
+/// ```compile_fail
+/// # use constcat::concat_ident_str;
+/// #
+/// const NAME: &str = concat_ident_str!("get-", "value");
+/// ```
+#[macro_export]
+macro_rules! concat_ident_str {
+    ($($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_ident_str($crate::concat!($($e),*));
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _ident_str(s: &str) -> &str {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        panic!("an identifier cannot be empty");
+    }
+    if bytes[0].is_ascii_digit() {
+        panic!("an identifier cannot start with a digit");
+    }
+    let mut i = 0;
+    while i < bytes.len() {
+        if !(bytes[i].is_ascii_alphanumeric() || bytes[i] == b'_') {
+            _Msg::new()
+                .str("invalid identifier character at byte index ")
+                .usize(i)
+                .panic();
+        }
+        i += 1;
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_unique!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice with
+/// duplicate elements removed.
+///
+/// This macro works exactly like [`concat_slices!`] except that only the first
+/// occurrence of each element across the whole concatenation is kept. The
+/// order of the first occurrences is preserved. Every element is compared with
+/// each element kept before it, so this is quadratic in the total length.
+///
+/// The element type must support the `==` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_unique;
+/// #
+/// const BUILTIN: &[u16] = &[80, 443, 8080];
+/// const EXTRA: &[u16] = &[22, 443, 80, 9000];
+/// const PORTS: &[u16] = concat_unique!([u16]: BUILTIN, EXTRA);
+/// assert_eq!(PORTS, [80, 443, 8080, 22, 9000]);
+/// ```
+#[macro_export]
+macro_rules! concat_unique {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        // The indices of the first occurrences and the number of them.
+        const FIRST: ([usize; SRC.len()], usize) = {
+            let mut idx = [0; SRC.len()];
+            let mut n = 0;
+            let mut i = 0;
+            while i < SRC.len() {
+                let mut j = 0;
+                while j < n && !(SRC[idx[j]] == SRC[i]) {
+                    j += 1;
+                }
+                if j == n {
+                    idx[n] = i;
+                    n += 1;
+                }
+                i += 1;
+            }
+            (idx, n)
+        };
+        const ARR: [$T; FIRST.1] = $crate::_from_fn!([$T; FIRST.1], |i| SRC[FIRST.0[i]]);
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_bswap!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice with the byte order of each field reversed.
+///
+/// This macro requires the field width to be specified in the form
+/// `width = <usize>; ` before the expressions accepted by [`concat_bytes!`].
+/// The operands are concatenated and then the bytes of every `width` sized
+/// field of the result are reversed, e.g. to convert a table of little-endian
+/// integers to big-endian. Since this happens after concatenation fields may
+/// span operands. The macro fails to compile if the total length is not a
+/// multiple of `width`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_bswap;
+/// #
+/// const LE: &[u8] = &[0x34, 0x12, 0x78];
+/// const REST: &[u8] = &[0x56];
+/// const BE: &[u8] = concat_bytes_bswap!(width = 2; LE, REST);
+/// assert_eq!(BE, [0x12, 0x34, 0x56, 0x78]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_bswap {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const ARR: [u8; SRC.len()] = $crate::_bswap(SRC, $width);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _bswap<const N: usize>(src: &[u8], width: usize) -> [u8; N] {
+    _chunk_count(src.len(), width);
+    let mut arr = [0; N];
+    let mut i = 0;
+    while i < N {
+        let field = i - i % width;
+        arr[i] = src[field + width - 1 - i % width];
+        i += 1;
+    }
+    arr
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_justify!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice padded to a fixed width.
+///
+/// This macro requires the options to be specified in the form
+/// `width = <usize>, align = <left|right>, fill = <char>; ` before the
+/// expressions accepted by [`concat!`]. The `align` and `fill` options may be
+/// omitted and default to `left` and `' '`. The operands are concatenated and
+/// then the result is padded with the fill character to exactly `width` bytes,
+/// placing the padding after the content for `left` alignment and before it
+/// for `right` alignment.
+///
+/// The macro fails to compile if the concatenation is longer than `width`
+/// bytes or if the fill character is not ASCII. Content is never truncated.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_justify;
+/// #
+/// const NAME: &str = "widget";
+/// const ROW: &str = concat_justify!(width = 10; NAME, ":");
+/// assert_eq!(ROW, "widget:   ");
+///
+/// const PRICE: &str = concat_justify!(width = 8, align = right, fill = '.'; 4, ".99");
+/// assert_eq!(PRICE, "....4.99");
+/// ```
+///
+/// The following fails to compile because the content is longer than the
+/// width.
+///
+/// ```compile_fail
+/// # use constcat::concat_justify;
+/// #
+/// const ROW: &str = concat_justify!(width = 4; "widget");
+/// ```
+#[macro_export]
+macro_rules! concat_justify {
+    (
+        width = $width:expr $(, align = $align:ident)? $(, fill = $fill:expr)?;
+        $($e:expr),* $(,)?
+    ) => {{
+        const ARR: [u8; $width] = $crate::_justify(
+            $crate::concat!($($e),*),
+            $crate::_justify_right!($($align)?),
+            $crate::_justify_fill!($($fill)?),
+        );
+        // SAFETY: The array contains the concatenated string and ASCII fill
+        // characters so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _justify_right {
+    () => {
+        false
+    };
+    (left) => {
+        false
+    };
+    (right) => {
+        true
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _justify_fill {
+    () => {
+        ' '
+    };
+    ($fill:expr) => {
+        $fill
+    };
+}
+
+#[doc(hidden)]
+pub const fn _justify<const N: usize>(s: &str, right: bool, fill: char) -> [u8; N] {
+    let bytes = s.as_bytes();
+    if bytes.len() > N {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(bytes.len())
+            .str(" exceeds the width ")
+            .usize(N)
+            .panic();
+    }
+    if !fill.is_ascii() {
+        panic!("fill character must be ASCII");
+    }
+    let mut arr = [fill as u8; N];
+    let start = if right { N - bytes.len() } else { 0 };
+    let mut i = 0;
+    while i < bytes.len() {
+        arr[start + i] = bytes[i];
+        i += 1;
+    }
+    arr
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_split_at!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// split it into two at an index.
+///
+/// This macro requires the type of slice and the index to be specified in the
+/// form `[T]: <usize>; ` before the comma separated expressions. It yields an
+/// expression of type `(&'static [T], &'static [T])` where the first element
+/// contains the elements of the concatenation before the index and the second
+/// element contains the rest. The macro fails to compile if the index is
+/// greater than the length of the concatenation.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_split_at;
+/// #
+/// const HEADER: &[u8] = &[0xCA, 0xFE, 0x00, 0x02];
+/// const BODY: &[u8] = &[0x10, 0x20];
+/// const PACKET: (&[u8], &[u8]) = concat_split_at!([u8]: 2; HEADER, BODY);
+/// assert_eq!(PACKET.0, [0xCA, 0xFE]);
+/// assert_eq!(PACKET.1, [0x00, 0x02, 0x10, 0x20]);
+/// ```
+#[macro_export]
+macro_rules! concat_split_at {
+    ([$T:ty]: $mid:expr; $($s:expr),* $(,)?) => {{
+        const TUPLE: (&[$T], &[$T]) = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let mid: usize = $mid;
+            if mid > arr.len() {
+                $crate::_Msg::new()
+                    .str("split index ")
+                    .usize(mid)
+                    .str(" is out of range for a concatenation of length ")
+                    .usize(arr.len())
+                    .panic();
+            }
+            arr.split_at(mid)
+        };
+        TUPLE
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_debug_decimal!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices and format the result as a static
+/// string slice of decimal values.
+///
+/// This macro takes the same input as [`concat_slices!`], optionally with a
+/// separator specified in the form `sep = <expr>; ` after the type, and yields
+/// an expression of type [`&'static str`][str]. The elements of the
+/// concatenation are formatted as decimal and joined with the separator, which
+/// defaults to `", "`. Negative values are prefixed with a `-`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_debug_decimal;
+/// #
+/// const A: &[i32] = &[1, -2];
+/// const B: &[i32] = &[3];
+/// const DEBUG: &str = concat_debug_decimal!([i32]: A, B);
+/// assert_eq!(DEBUG, "1, -2, 3");
+///
+/// const LINES: &str = concat_debug_decimal!([i32]: sep = "\n"; A, B);
+/// assert_eq!(LINES, "1\n-2\n3");
+/// ```
+#[macro_export]
+macro_rules! concat_debug_decimal {
+    ([$T:ty]: sep = $sep:expr; $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const INTS: [(bool, u128); SRC.len()] =
+            $crate::_from_fn!([(bool, u128); SRC.len()], |i| $crate::_int!(SRC[i]));
+        const LEN: usize = $crate::_int_csv::<0>(&INTS, $sep).len();
+        const ARR: [u8; LEN] = $crate::_int_csv::<LEN>(&INTS, $sep).finish();
+        // SAFETY: The array only contains ASCII digits, `-`, and the bytes of
+        // the separator string so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::concat_debug_decimal!([$T]: sep = ", "; $($s),*)
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_str_slices!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[&str]`][slice] expressions into a static slice of
+/// string slices.
+///
+/// This macro takes any number of comma-separated [`&[&str]`][slice] or
+/// `[&str; N]` expressions and yields an expression of type
+/// [`&'static [&'static str]`][slice] containing all of the string slices in
+/// order. Unlike [`concat!`] the strings themselves are not joined, this is
+/// shorthand for [`concat_slices!`] with the element type `&'static str`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_str_slices;
+/// #
+/// const READ: [&str; 2] = ["cat", "ls"];
+/// const WRITE: &[&str] = &["cp", "mv", "rm"];
+/// const COMMANDS: &[&str] = concat_str_slices!(READ, WRITE);
+/// assert_eq!(COMMANDS, ["cat", "ls", "cp", "mv", "rm"]);
+/// ```
+#[macro_export]
+macro_rules! concat_str_slices {
+    ($($s:expr),* $(,)?) => {
+        $crate::concat_slices!([&'static str]: $($s),*)
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_strip_prefix! and concat_strip_suffix!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and remove a prefix if present.
+///
+/// This macro requires the prefix to be specified in the form `<&str>; `
+/// before the expressions accepted by [`concat!`]. The operands are
+/// concatenated and then if the result starts with the prefix a single
+/// occurrence of it is removed, otherwise the result is unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_strip_prefix;
+/// #
+/// const PATH: &str = "./";
+/// const FILE: &str = concat_strip_prefix!("./"; PATH, "src/lib.rs");
+/// assert_eq!(FILE, "src/lib.rs");
+/// ```
+#[macro_export]
+macro_rules! concat_strip_prefix {
+    ($pat:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_strip_prefix($crate::concat!($($e),*), $pat);
+        STR
+    }};
+}
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and remove a suffix if present.
+///
+/// This macro requires the suffix to be specified in the form `<&str>; `
+/// before the expressions accepted by [`concat!`]. The operands are
+/// concatenated and then if the result ends with the suffix a single
+/// occurrence of it is removed, otherwise the result is unchanged.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_strip_suffix;
+/// #
+/// const LINE: &str = concat_strip_suffix!("\n"; "key", " = ", "value\n");
+/// assert_eq!(LINE, "key = value");
+/// ```
+#[macro_export]
+macro_rules! concat_strip_suffix {
+    ($pat:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_strip_suffix($crate::concat!($($e),*), $pat);
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _strip_prefix<'a>(s: &'a str, pat: &str) -> &'a str {
+    let bytes = s.as_bytes();
+    if !_starts_with_at(bytes, 0, pat.as_bytes()) {
+        return s;
+    }
+    // SAFETY: The prefix is a whole string so the remaining bytes start on a
+    // character boundary and are still valid UTF-8.
+    unsafe { core::str::from_utf8_unchecked(_subslice(bytes, pat.len(), bytes.len())) }
+}
+
+#[doc(hidden)]
+pub const fn _strip_suffix<'a>(s: &'a str, pat: &str) -> &'a str {
+    let bytes = s.as_bytes();
+    if pat.len() > bytes.len() || !_starts_with_at(bytes, bytes.len() - pat.len(), pat.as_bytes()) {
+        return s;
+    }
+    // SAFETY: The suffix is a whole string so the remaining bytes end on a
+    // character boundary and are still valid UTF-8.
+    unsafe { core::str::from_utf8_unchecked(_subslice(bytes, 0, bytes.len() - pat.len())) }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_parity!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// assert that the number of elements is even or odd.
+///
+/// This macro requires the type of slice and the parity to be specified in the
+/// form `[T]: even; ` or `[T]: odd; ` before the comma separated expressions.
+/// It works exactly like [`concat_slices!`] except that it fails to compile if
+/// the length of the result does not have the given parity. The panic message
+/// states the actual length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_parity;
+/// #
+/// const PAIRS: &[u8] = &[b'a', 1, b'b', 2];
+/// const MORE: &[u8] = &[b'c', 3];
+/// const KV: &[u8] = concat_parity!([u8]: even; PAIRS, MORE);
+/// ```
+///
+/// The following fails to compile because the value for `b'c'` is missing.
+///
+/// ```compile_fail
+/// # use constcat::concat_parity;
+/// #
+/// const KV: &[u8] = concat_parity!([u8]: even; &[b'a', 1], &[b'c']);
+/// ```
+#[macro_export]
+macro_rules! concat_parity {
+    ([$T:ty]: even; $($s:expr),* $(,)?) => {
+        $crate::_concat_parity!([$T]: false; $($s),*)
+    };
+    ([$T:ty]: odd; $($s:expr),* $(,)?) => {
+        $crate::_concat_parity!([$T]: true; $($s),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_parity {
+    ([$T:ty]: $odd:expr; $($s:expr),*) => {{
+        const ARR: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const _: () = $crate::_parity(ARR.len(), $odd);
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _parity(len: usize, odd: bool) {
+    if (len % 2 == 1) != odd {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(len)
+            .str(if odd { " is not odd" } else { " is not even" })
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_wrap! and concat_bytes_wrap!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice surrounded by delimiters.
+///
+/// This macro requires the delimiters to be specified in the form
+/// `open = <&str>, close = <&str>; ` before the expressions accepted by
+/// [`concat!`]. The result is the `open` delimiter followed by the
+/// concatenation of the operands followed by the `close` delimiter.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_wrap;
+/// #
+/// const ITEMS: &str = "1, 2, 3";
+/// const LIST: &str = concat_wrap!(open = "[", close = "]"; ITEMS, ", 4");
+/// assert_eq!(LIST, "[1, 2, 3, 4]");
+/// ```
+#[macro_export]
+macro_rules! concat_wrap {
+    (open = $open:expr, close = $close:expr; $($e:expr),* $(,)?) => {
+        $crate::concat!($open, $($e,)* $close)
+    };
+}
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice surrounded by delimiters.
+///
+/// This macro requires the delimiters to be specified in the form
+/// `open = <&[u8]>, close = <&[u8]>; ` before the expressions accepted by
+/// [`concat_bytes!`]. The result is the `open` delimiter followed by the
+/// concatenation of the operands followed by the `close` delimiter.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_wrap;
+/// #
+/// const STX: &[u8] = &[0x02];
+/// const ETX: &[u8] = &[0x03];
+/// const PAYLOAD: &[u8] = &[0x10, 0x20];
+/// const FRAME: &[u8] = concat_bytes_wrap!(open = STX, close = ETX; PAYLOAD);
+/// assert_eq!(FRAME, [0x02, 0x10, 0x20, 0x03]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_wrap {
+    (open = $open:expr, close = $close:expr; $($e:expr),* $(,)?) => {
+        $crate::concat_bytes!($open, $($e,)* $close)
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_offset_table!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Place `const` [`&[u8]`][slice] expressions and literals at strictly
+/// increasing offsets in a static byte slice.
+///
+/// This macro takes the same input as [`concat_bytes_at!`] and yields the same
+/// output, but it additionally requires the pairs to be given in layout order.
+/// The macro fails to compile unless each offset is strictly greater than the
+/// one before it and each operand ends at or before the offset of the next.
+/// The panic message names the offending operand and the offsets involved.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_offset_table;
+/// #
+/// const MAGIC: &[u8] = &[0xCA, 0xFE];
+/// const COUNT: &[u8] = &[0x00, 0x03];
+/// const TABLE: &[u8] = concat_offset_table!((MAGIC, 0), (COUNT, 4), (&[0xFF], 6));
+/// assert_eq!(TABLE, [0xCA, 0xFE, 0x00, 0x00, 0x00, 0x03, 0xFF]);
+/// ```
+///
+/// The following fails to compile because the offsets are out of order.
+///
+/// ```compile_fail
+/// # use constcat::concat_offset_table;
+/// #
+/// const TABLE: &[u8] = concat_offset_table!((&[1], 4), (&[2], 0));
+/// ```
+#[macro_export]
+macro_rules! concat_offset_table {
+    ($(($e:expr, $offset:expr)),* $(,)?) => {{
+        const PARTS: &[(&[u8], usize)] = &[$(($crate::concat_bytes!($e), $offset)),*];
+        const LEN: usize = {
+            $crate::_offset_table(PARTS);
+            $crate::_bytes_at_len(PARTS)
+        };
+        const ARR: [u8; LEN] = $crate::_bytes_at(PARTS);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _offset_table(parts: &[(&[u8], usize)]) {
+    let mut i = 1;
+    while i < parts.len() {
+        let (prev, prev_offset) = parts[i - 1];
+        let offset = parts[i].1;
+        if offset <= prev_offset {
+            _Msg::new()
+                .str("offset ")
+                .usize(offset)
+                .str(" of operand ")
+                .usize(i)
+                .str(" is not greater than the previous offset ")
+                .usize(prev_offset)
+                .panic();
+        }
+        if prev_offset + prev.len() > offset {
+            _Msg::new()
+                .str("operand ")
+                .usize(i - 1)
+                .str(" ends at ")
+                .usize(prev_offset + prev.len())
+                .str(" past the next offset ")
+                .usize(offset)
+                .panic();
+        }
+        i += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_len_str!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Format the length of the concatenation of `const` [`&[T]`][slice]
+/// expressions as a static decimal string slice.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static str`][str] containing the number of elements
+/// in the concatenation formatted as decimal. For `[u8]` this is the length in
+/// bytes, e.g. for a `Content-Length` header.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat, concat_len_str};
+/// #
+/// const HEAD: &[u8] = b"<html>";
+/// const TAIL: &[u8] = b"</html>";
+/// const LENGTH: &str = concat!("Content-Length: ", concat_len_str!([u8]: HEAD, TAIL));
+/// assert_eq!(LENGTH, "Content-Length: 13");
+/// ```
+#[macro_export]
+macro_rules! concat_len_str {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const LEN: usize = $crate::_Buf::<0>::new().usize(SRC.len()).len();
+        const ARR: [u8; LEN] = $crate::_Buf::<LEN>::new().usize(SRC.len()).finish();
+        // SAFETY: The array only contains ASCII digits.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_utf8_aligned!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions into a static string slice
+/// and assert that no character spans two operands.
+///
+/// This macro takes the same input as [`concat_slices!`] with the type `[u8]`
+/// and yields an expression of type [`&'static str`][str]. It fails to compile
+/// if the concatenation is not valid UTF-8 or if any boundary between two
+/// operands falls inside a multi-byte character. The panic message names the
+/// byte index of the invalid sequence or of the offending boundary.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_utf8_aligned;
+/// #
+/// const CAFE: &[u8] = "café".as_bytes();
+/// const BAR: &[u8] = " bar".as_bytes();
+/// const TEXT: &str = concat_utf8_aligned!([u8]: CAFE, BAR);
+/// assert_eq!(TEXT, "café bar");
+/// ```
+///
+/// The following fails to compile because `é` is split across the operands
+/// even though the result is valid UTF-8.
+///
+/// ```compile_fail
+/// # use constcat::concat_utf8_aligned;
+/// #
+/// const TEXT: &str = concat_utf8_aligned!([u8]: &[b'c', 0xC3], &[0xA9]);
+/// ```
+#[macro_export]
+macro_rules! concat_utf8_aligned {
+    ([u8]: $($s:expr),* $(,)?) => {{
+        const LENS: &[usize] = &[$($crate::concat_slices!([u8]: $s).len()),*];
+        const STR: &str = $crate::_utf8_aligned($crate::concat_slices!([u8]: $($s),*), LENS);
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _utf8_aligned<'a>(bytes: &'a [u8], lens: &[usize]) -> &'a str {
+    let s = match core::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(err) => _Msg::new()
+            .str("invalid UTF-8 at byte index ")
+            .usize(err.valid_up_to())
+            .panic(),
+    };
+    let mut offset = 0;
+    let mut i = 0;
+    while i < lens.len() {
+        offset += lens[i];
+        // Continuation bytes are the only bytes that do not start a character.
+        if offset < bytes.len() && bytes[offset] & 0xC0 == 0x80 {
+            _Msg::new()
+                .str("operand boundary at byte index ")
+                .usize(offset)
+                .str(" splits a character")
+                .panic();
+        }
+        i += 1;
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_rle! and concat_rle_decode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// run-length encoded byte slice.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static [u8]`][slice]. The operands are concatenated
+/// and then each run of equal bytes is encoded as a `count, value` pair, where
+/// `count` is between 1 and 255. Runs longer than 255 bytes are split into
+/// multiple pairs. Since this happens after concatenation a run may span
+/// operands. Use [`concat_rle_decode!`] to reverse the encoding.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_rle;
+/// #
+/// const BLANK: &[u8] = &[0; 300];
+/// const PIXELS: &[u8] = &[0, 0xFF, 0xFF];
+/// const ENCODED: &[u8] = concat_rle!(BLANK, PIXELS);
+/// assert_eq!(ENCODED, [255, 0, 46, 0, 2, 0xFF]);
+/// ```
+#[macro_export]
+macro_rules! concat_rle {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_rle::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_rle::<LEN>(SRC).finish();
+        &ARR
+    }};
+}
+
+/// Concatenate `const` run-length encoded [`&[u8]`][slice] expressions and
+/// literals and decode them into a static byte slice.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static [u8]`][slice]. The concatenation is decoded
+/// as a sequence of `count, value` pairs in the format produced by
+/// [`concat_rle!`], each pair expanding to `count` copies of `value`. A count
+/// of zero is allowed and expands to nothing. The macro fails to compile if the
+/// concatenation has an odd length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_rle, concat_rle_decode};
+/// #
+/// const ENCODED: &[u8] = &[3, b'a', 1, b'b'];
+/// const DECODED: &[u8] = concat_rle_decode!(ENCODED, &[2, b'c']);
+/// assert_eq!(DECODED, b"aaabcc");
+///
+/// const DATA: &[u8] = &[7, 7, 7, 0, 1, 1];
+/// assert_eq!(concat_rle_decode!(concat_rle!(DATA)), DATA);
+/// ```
+#[macro_export]
+macro_rules! concat_rle_decode {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_rle_decode::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_rle_decode::<LEN>(SRC).finish();
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _rle<const N: usize>(src: &[u8]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        let mut count = 1;
+        while count < 255 && i + count < src.len() && src[i + count] == src[i] {
+            count += 1;
+        }
+        buf = buf.push(count as u8).push(src[i]);
+        i += count;
+    }
+    buf
+}
+
+#[doc(hidden)]
+pub const fn _rle_decode<const N: usize>(src: &[u8]) -> _Buf<N> {
+    if src.len() % 2 != 0 {
+        _Msg::new()
+            .str("run-length encoded data has odd length ")
+            .usize(src.len())
+            .panic();
+    }
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        let mut count = 0;
+        while count < src[i] {
+            buf = buf.push(src[i + 1]);
+            count += 1;
+        }
+        i += 2;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_maxline!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert a maximum line length.
+///
+/// This macro requires the maximum width to be specified in the form
+/// `width = <usize>; ` before the expressions accepted by [`concat!`]. It
+/// works exactly like [`concat!`] except that it fails to compile if any line
+/// of the result is longer than `width` bytes. Lines are separated by `\n`
+/// and the separator is not counted. The panic message names the offending
+/// line, counting from one, and its length.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_maxline;
+/// #
+/// const USAGE: &str = "usage: tool [OPTIONS]\n";
+/// const HELP: &str = concat_maxline!(width = 24; USAGE, "  -h  print help\n");
+/// ```
+///
+/// The following fails to compile because the second line is 31 bytes long.
+///
+/// ```compile_fail
+/// # use constcat::concat_maxline;
+/// #
+/// const HELP: &str = concat_maxline!(width = 24; "usage:\n", "this line is far too long to fit");
+/// ```
+#[macro_export]
+macro_rules! concat_maxline {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_maxline($crate::concat!($($e),*), $width);
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _maxline(s: &str, width: usize) -> &str {
+    let bytes = s.as_bytes();
+    let mut line = 1;
+    let mut start = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == b'\n' {
+            if i - start > width {
+                _Msg::new()
+                    .str("line ")
+                    .usize(line)
+                    .str(" has length ")
+                    .usize(i - start)
+                    .str(" which exceeds the maximum of ")
+                    .usize(width)
+                    .panic();
+            }
+            line += 1;
+            start = i + 1;
+        }
+        i += 1;
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_chunked_len!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice along
+/// with the number of fixed size chunks in it.
+///
+/// This macro takes the same input as [`concat_chunks!`] and yields an
+/// expression of type `(&'static [T], usize)`. The first element is the
+/// concatenation and the second element is the number of `S` sized chunks in
+/// it. Unlike [`concat_chunks!`] the chunks are not materialized. The macro
+/// fails to compile if the total length is not a multiple of `S`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_chunked_len;
+/// #
+/// const A: &[u16] = &[1, 2, 3, 4];
+/// const B: &[u16] = &[5, 6];
+/// const DATA: (&[u16], usize) = concat_chunked_len!([u16]: size = 2; A, B);
+/// assert_eq!(DATA, ([1, 2, 3, 4, 5, 6].as_slice(), 3));
+///
+/// const CHUNKS: usize = DATA.1;
+/// const FIRST: [u16; CHUNKS] = [0; CHUNKS];
+/// ```
+#[macro_export]
+macro_rules! concat_chunked_len {
+    ([$T:ty]: size = $size:expr; $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const TUPLE: (&[$T], usize) = (SRC, $crate::_chunk_count(SRC.len(), $size));
+        TUPLE
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// export_concat_bytes!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte array exported under an unmangled symbol name.
+///
+/// This macro is used in item position and takes a symbol name followed by a
+/// comma and then any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`]. It defines a `pub static` of type `[u8; N]`, where `N` is
+/// the exact length of the concatenation, that is visible to the linker so
+/// that it can be referenced from C or other languages.
+///
+/// The symbol name may be given as an identifier, in which case the static is
+/// `#[no_mangle]` and can also be used by that name from Rust. Otherwise it can
+/// be given as a string literal, in which case the static is only reachable
+/// through the linker using `#[export_name]`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::export_concat_bytes;
+/// #
+/// const MAGIC: &[u8] = &[0x7F, 0x45, 0x4C, 0x46];
+/// const VERSION: &[u8] = &[1];
+///
+/// export_concat_bytes!(header_table, MAGIC, VERSION);
+/// export_concat_bytes!("version_table", VERSION);
+///
+/// # fn main() {
+/// assert_eq!(header_table, [0x7F, 0x45, 0x4C, 0x46, 0x01]);
+/// # }
+/// ```
+///
+/// The corresponding declarations in C would be the following.
+///
+/// ```c
+/// extern const unsigned char header_table[5];
+/// extern const unsigned char version_table[1];
+/// ```
+#[macro_export]
+macro_rules! export_concat_bytes {
+    ($name:ident, $($e:expr),* $(,)?) => {
+        #[no_mangle]
+        pub static $name: [u8; $crate::concat_bytes!($($e),*).len()] =
+            $crate::_Buf::new().bytes($crate::concat_bytes!($($e),*)).finish();
+    };
+
+    ($name:literal, $($e:expr),* $(,)?) => {
+        const _: () = {
+            const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+            #[export_name = $name]
+            pub static ARR: [u8; SRC.len()] = $crate::_Buf::new().bytes(SRC).finish();
+        };
+    };
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_mode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the most common
+/// element of the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the element that occurs most often in the
+/// concatenation. If several elements occur equally often the one that occurs
+/// first is returned. Every element is counted against every other element, so
+/// this is quadratic in the total length. The macro fails to compile if the
+/// concatenation is empty.
+///
+/// The element type must support the `==` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_mode;
+/// #
+/// const A: &[u8] = &[3, 1, 4, 1];
+/// const B: &[u8] = &[5, 9, 2, 6, 5];
+/// const MODE: u8 = concat_mode!([u8]: A, B);
+/// assert_eq!(MODE, 1);
+/// ```
+#[macro_export]
+macro_rules! concat_mode {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const MODE: $T = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            if arr.is_empty() {
+                panic!("cannot take the mode of an empty concatenation");
+            }
+            let mut best = 0;
+            let mut best_count = 0;
+            let mut i = 0;
+            while i < arr.len() {
+                let mut count = 0;
+                let mut j = i;
+                while j < arr.len() {
+                    if arr[j] == arr[i] {
+                        count += 1;
+                    }
+                    j += 1;
+                }
+                if count > best_count {
+                    best = i;
+                    best_count = count;
+                }
+                i += 1;
+            }
+            arr[best]
+        };
+        MODE
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_assert_golden!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that it matches a golden file.
+///
+/// This macro takes a file path literal followed by a comma and then any
+/// number of comma-separated expressions as accepted by [`concat_bytes!`]. It
+/// works exactly like [`concat_bytes!`] except that it fails to compile unless
+/// the result is identical to the contents of the file. The path is resolved
+/// the same way as [`include_bytes!`], i.e. relative to the file that the macro
+/// is used in. The panic message names the first offset at which the contents
+/// differ along with both bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_assert_golden;
+/// #
+/// const MAGIC: &[u8] = &[b'G', b'O', b'L', b'D'];
+/// const VERSION: &[u8] = &[0, 1];
+/// const TABLE: &[u8] = concat_assert_golden!("../tests/golden.bin", MAGIC, VERSION);
+/// ```
+///
+/// The following fails to compile because the version does not match.
+///
+/// ```compile_fail
+/// # use constcat::concat_assert_golden;
+/// #
+/// const MAGIC: &[u8] = &[b'G', b'O', b'L', b'D'];
+/// const TABLE: &[u8] = concat_assert_golden!("../tests/golden.bin", MAGIC, &[0, 2]);
+/// ```
+///
+/// [`include_bytes!`]: core::include_bytes
+#[macro_export]
+macro_rules! concat_assert_golden {
+    ($path:literal, $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_assert_golden(ARR, $crate::core::include_bytes!($path));
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _assert_golden(actual: &[u8], golden: &[u8]) {
+    let mut i = 0;
+    while i < actual.len() && i < golden.len() {
+        if actual[i] != golden[i] {
+            _Msg::new()
+                .str("concatenation differs from the golden file at offset ")
+                .usize(i)
+                .str(": expected 0x")
+                .hex_lower(golden[i])
+                .str(", found 0x")
+                .hex_lower(actual[i])
+                .panic();
+        }
+        i += 1;
+    }
+    if actual.len() != golden.len() {
+        _Msg::new()
+            .str("concatenation length ")
+            .usize(actual.len())
+            .str(" differs from the golden file length ")
+            .usize(golden.len())
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_template!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Substitute `const` values into the `{name}` placeholders of a `const`
+/// template string.
+///
+/// This macro takes a template [`&str`][str] expression followed by any number
+/// of comma-separated `name = value` pairs, where each value is a literal or
+/// `const` expression as accepted by [`concat!`]. It yields an expression of
+/// type [`&'static str`][str] where every `{name}` placeholder in the template
+/// is replaced with the corresponding value. Use `{{` and `}}` for literal
+/// braces. A placeholder may be used any number of times, or not at all.
+///
+/// The macro fails to compile if the template contains a placeholder that has
+/// no value or an unmatched brace. The panic message names the byte index of
+/// the offending placeholder or brace.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_template;
+/// #
+/// const TEMPLATE: &str = "Hello, {name}! You are {age} years old. {{name}}";
+/// const GREETING: &str = concat_template!(TEMPLATE, name = "world", age = 42);
+/// assert_eq!(GREETING, "Hello, world! You are 42 years old. {name}");
+/// ```
+///
+/// The following fails to compile because there is no value for `{age}`.
+///
+/// ```compile_fail
+/// # use constcat::concat_template;
+/// #
+/// const GREETING: &str = concat_template!("{name} is {age}", name = "world");
+/// ```
+#[macro_export]
+macro_rules! concat_template {
+    ($template:expr $(, $name:ident = $value:expr)* $(,)?) => {{
+        const NAMES: &[&str] = &[$($crate::core::stringify!($name)),*];
+        const VALUES: &[&str] = &[$($crate::_maybe_std_concat!($value)),*];
+        const LEN: usize = $crate::_template::<0>($template, NAMES, VALUES).len();
+        const ARR: [u8; LEN] = $crate::_template::<LEN>($template, NAMES, VALUES).finish();
+        // SAFETY: The array only contains the bytes of the template and the
+        // values, split at ASCII braces, so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _template<const N: usize>(template: &str, names: &[&str], values: &[&str]) -> _Buf<N> {
+    let bytes = template.as_bytes();
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if (b == b'{' || b == b'}') && i + 1 < bytes.len() && bytes[i + 1] == b {
+            buf = buf.push(b);
+            i += 2;
+            continue;
+        }
+        if b == b'}' {
+            _Msg::new()
+                .str("unmatched `}` in template at byte index ")
+                .usize(i)
+                .panic();
+        }
+        if b != b'{' {
+            buf = buf.push(b);
+            i += 1;
+            continue;
+        }
+        let start = i + 1;
+        let mut end = start;
+        while end < bytes.len() && bytes[end] != b'}' {
+            end += 1;
+        }
+        if end == bytes.len() {
+            _Msg::new()
+                .str("unmatched `{` in template at byte index ")
+                .usize(i)
+                .panic();
+        }
+        let name = _subslice(bytes, start, end);
+        let mut k = 0;
+        while k < names.len() && !_bytes_eq(names[k].as_bytes(), name) {
+            k += 1;
+        }
+        if k == names.len() {
+            _Msg::new()
+                .str("no value for template placeholder `{")
+                .bytes(name)
+                .str("}` at byte index ")
+                .usize(i)
+                .panic();
+        }
+        buf = buf.str(values[k]);
+        i = end + 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_sum! and concat_product!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices and return the sum of the elements.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the sum of all of the elements of the
+/// concatenation, or zero if it is empty. The macro fails to compile if the sum
+/// overflows `T`. The panic message names the index of the element at which it
+/// overflowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_sum;
+/// #
+/// const A: &[u32] = &[10, 20];
+/// const B: &[u32] = &[30];
+/// const TOTAL: u32 = concat_sum!([u32]: A, B);
+/// assert_eq!(TOTAL, 60);
+/// ```
+///
+/// The following fails to compile because the sum overflows `u8`.
+///
+/// ```compile_fail
+/// # use constcat::concat_sum;
+/// #
+/// const TOTAL: u8 = concat_sum!([u8]: &[200], &[100]);
+/// ```
+#[macro_export]
+macro_rules! concat_sum {
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::_concat_fold!([$T]: 0, checked_add, "sum"; $($s),*)
+    };
+}
+
+/// Concatenate `const` integer slices and return the product of the elements.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `T` which is the product of all of the elements of the
+/// concatenation, or one if it is empty. The macro fails to compile if the
+/// product overflows `T`. The panic message names the index of the element at
+/// which it overflowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_product;
+/// #
+/// const DIMS: &[u64] = &[2, 3];
+/// const DEPTH: &[u64] = &[4];
+/// const VOLUME: u64 = concat_product!([u64]: DIMS, DEPTH);
+/// assert_eq!(VOLUME, 24);
+/// ```
+#[macro_export]
+macro_rules! concat_product {
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::_concat_fold!([$T]: 1, checked_mul, "product"; $($s),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_fold {
+    ([$T:ty]: $init:literal, $op:ident, $what:literal; $($s:expr),*) => {{
+        const ACC: $T = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let mut acc: $T = $init;
+            let mut i = 0;
+            while i < arr.len() {
+                acc = match acc.$op(arr[i]) {
+                    Some(acc) => acc,
+                    None => $crate::_Msg::new()
+                        .str($crate::core::concat!("the ", $what, " overflows at index "))
+                        .usize(i)
+                        .panic(),
+                };
+                i += 1;
+            }
+            acc
+        };
+        ACC
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_argsort!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions and return the indices that
+/// would sort the result.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static [usize]`][slice] containing the indices of
+/// the elements of the concatenation in ascending order of the elements. The
+/// data itself is not reordered. The sort is stable, so the indices of equal
+/// elements are in ascending order. It is an insertion sort, so it is
+/// quadratic in the total length.
+///
+/// The element type must support the `<` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_argsort;
+/// #
+/// const A: &[u8] = &[30, 10];
+/// const B: &[u8] = &[20, 10];
+/// const ORDER: &[usize] = concat_argsort!([u8]: A, B);
+/// assert_eq!(ORDER, [1, 3, 2, 0]);
+/// ```
+#[macro_export]
+macro_rules! concat_argsort {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const IDX: [usize; SRC.len()] = {
+            let mut idx = [0; SRC.len()];
+            let mut i = 0;
+            while i < idx.len() {
+                let mut j = i;
+                while j > 0 && SRC[i] < SRC[idx[j - 1]] {
+                    idx[j] = idx[j - 1];
+                    j -= 1;
+                }
+                idx[j] = i;
+                i += 1;
+            }
+            idx
+        };
+        &IDX
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_regions!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Encode `const` `(start, len)` regions into a static byte slice and assert
+/// that no two regions overlap.
+///
+/// This macro takes any number of comma-separated `(start, len)` pairs where
+/// both are `u64` expressions and yields an expression of type
+/// [`&'static [u8]`][slice]. Each region is encoded as its start followed by
+/// its length, both as eight little-endian bytes, so the result is sixteen
+/// bytes per region. The regions may be given in any order, but the macro
+/// fails to compile if any two regions overlap or if a region extends past
+/// `u64::MAX`. The panic message names the overlapping regions. An empty region
+/// never overlaps.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_regions;
+/// #
+/// const FLASH: u64 = 0x0800_0000;
+/// const TABLE: &[u8] = concat_regions!((FLASH, 0x100), (FLASH + 0x100, 0x400));
+/// assert_eq!(TABLE.len(), 32);
+/// assert_eq!(TABLE[..16], [0, 0, 0, 8, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+/// ```
+///
+/// The following fails to compile because the regions overlap at `16`.
+///
+/// ```compile_fail
+/// # use constcat::concat_regions;
+/// #
+/// const TABLE: &[u8] = concat_regions!((0, 17), (16, 32));
+/// ```
+#[macro_export]
+macro_rules! concat_regions {
+    ($(($start:expr, $len:expr)),* $(,)?) => {{
+        const REGIONS: &[(u64, u64)] = &[$(($start, $len)),*];
+        const ARR: [u8; REGIONS.len() * 16] = $crate::_regions(REGIONS);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _regions<const N: usize>(regions: &[(u64, u64)]) -> [u8; N] {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < regions.len() {
+        let (start, len) = regions[i];
+        if start.checked_add(len).is_none() {
+            _Msg::new()
+                .str("region ")
+                .usize(i)
+                .str(" extends past the end of the address space")
+                .panic();
+        }
+        let mut j = 0;
+        while j < i {
+            let (other, other_len) = regions[j];
+            let overlaps = start < other + other_len && other < start + len;
+            if overlaps && len != 0 && other_len != 0 {
+                _Msg::new()
+                    .str("region ")
+                    .usize(i)
+                    .str(" at ")
+                    .u128(start as u128)
+                    .str(" overlaps region ")
+                    .usize(j)
+                    .str(" at ")
+                    .u128(other as u128)
+                    .panic();
+            }
+            j += 1;
+        }
+        buf = buf.bytes(&start.to_le_bytes()).bytes(&len.to_le_bytes());
+        i += 1;
+    }
+    buf.finish()
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_json_numbers!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices and format the result as a static JSON
+/// array string slice.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static str`][str] containing a JSON array of the
+/// elements of the concatenation formatted as decimal, with no whitespace,
+/// e.g. `[1,-2,3]`. The elements may be of any integer type, but note that
+/// many JSON parsers cannot represent integers larger than `2^53` exactly.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_json_numbers;
+/// #
+/// const A: &[i32] = &[1, -2];
+/// const B: &[i32] = &[3];
+/// const JSON: &str = concat_json_numbers!([i32]: A, B);
+/// assert_eq!(JSON, "[1,-2,3]");
+/// ```
+#[macro_export]
+macro_rules! concat_json_numbers {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const INTS: [(bool, u128); SRC.len()] =
+            $crate::_from_fn!([(bool, u128); SRC.len()], |i| $crate::_int!(SRC[i]));
+        const LEN: usize = $crate::_json_numbers::<0>(&INTS).len();
+        const ARR: [u8; LEN] = $crate::_json_numbers::<LEN>(&INTS).finish();
+        // SAFETY: The array only contains ASCII characters.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _json_numbers<const N: usize>(ints: &[(bool, u128)]) -> _Buf<N> {
+    let mut buf = _Buf::new().push(b'[');
+    let mut i = 0;
+    while i < ints.len() {
+        if i > 0 {
+            buf = buf.push(b',');
+        }
+        buf = buf.int(ints[i]);
+        i += 1;
+    }
+    buf.push(b']')
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bracketed_magic! and assert_bracketed!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice with a magic number at both ends.
+///
+/// This macro requires the magic number to be specified in the form
+/// `magic = <&[u8]>; ` before the expressions accepted by [`concat_bytes!`].
+/// The result is the magic number followed by the concatenation of the
+/// operands followed by the magic number again. Use [`assert_bracketed!`] to
+/// check the framing of a blob.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bracketed_magic;
+/// #
+/// const MAGIC: &[u8] = &[0xDE, 0xAD];
+/// const BODY: &[u8] = &[1, 2, 3];
+/// const BLOB: &[u8] = concat_bracketed_magic!(magic = MAGIC; BODY);
+/// assert_eq!(BLOB, [0xDE, 0xAD, 1, 2, 3, 0xDE, 0xAD]);
+/// ```
+#[macro_export]
+macro_rules! concat_bracketed_magic {
+    (magic = $magic:expr; $($e:expr),* $(,)?) => {
+        $crate::concat_bytes!($magic, $($e,)* $magic)
+    };
+}
+
+/// Assert that a `const` byte slice expression starts and ends with a magic
+/// number.
+///
+/// This macro takes a constant [`&[u8]`][slice] expression, such as the output
+/// of [`concat_bracketed_magic!`] or [`include_bytes!`], and a magic number
+/// [`&[u8]`][slice] expression. It fails to compile unless the slice starts
+/// with the magic number and separately ends with it, i.e. the slice must be
+/// at least twice as long as the magic number. The panic message states which
+/// end does not match.
+///
+/// This macro can be used anywhere an item or statement is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{assert_bracketed, concat_bracketed_magic};
+/// #
+/// const MAGIC: &[u8] = &[0xDE, 0xAD];
+/// const BLOB: &[u8] = concat_bracketed_magic!(magic = MAGIC; &[1, 2, 3]);
+/// assert_bracketed!(BLOB, MAGIC);
+/// ```
+///
+/// The following fails to compile because the footer is missing.
+///
+/// ```compile_fail
+/// # use constcat::assert_bracketed;
+/// #
+/// const MAGIC: &[u8] = &[0xDE, 0xAD];
+/// assert_bracketed!(&[0xDE, 0xAD, 1, 2, 3], MAGIC);
+/// ```
+///
+/// [`include_bytes!`]: core::include_bytes
+#[macro_export]
+macro_rules! assert_bracketed {
+    ($e:expr, $magic:expr $(,)?) => {
+        const _: () = $crate::_assert_bracketed($e, $magic);
+    };
+}
+
+#[doc(hidden)]
+pub const fn _assert_bracketed(blob: &[u8], magic: &[u8]) {
+    if blob.len() < 2 * magic.len() {
+        _Msg::new()
+            .str("blob of length ")
+            .usize(blob.len())
+            .str(" is too short to hold a header and footer of length ")
+            .usize(magic.len())
+            .panic();
+    }
+    if !_starts_with_at(blob, 0, magic) {
+        panic!("blob does not start with the magic number");
+    }
+    if !_starts_with_at(blob, blob.len() - magic.len(), magic) {
+        panic!("blob does not end with the magic number");
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_require_distinct_count!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert a minimum number of distinct byte values.
+///
+/// This macro requires the minimum to be specified in the form
+/// `min = <usize>; ` before the expressions accepted by [`concat_bytes!`]. It
+/// works exactly like [`concat_bytes!`] except that it fails to compile if the
+/// result contains fewer than `min` distinct byte values. This guards against
+/// accidentally embedding low entropy data, such as a key that is all zeroes.
+/// The panic message states the actual number of distinct values.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_require_distinct_count;
+/// #
+/// const SEED: &[u8] = &[0x3A, 0x91, 0x5C, 0xE7];
+/// const NONCE: &[u8] = &[0x08, 0xB2, 0x6F, 0xD4];
+/// const KEY: &[u8] = concat_bytes_require_distinct_count!(min = 8; SEED, NONCE);
+/// ```
+///
+/// The following fails to compile because the key only contains zeroes.
+///
+/// ```compile_fail
+/// # use constcat::concat_bytes_require_distinct_count;
+/// #
+/// const KEY: &[u8] = concat_bytes_require_distinct_count!(min = 8; &[0; 16]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_require_distinct_count {
+    (min = $min:expr; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_require_distinct_count(ARR, $min);
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _require_distinct_count(bytes: &[u8], min: usize) {
+    let mut seen = [false; 256];
+    let mut count = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !seen[bytes[i] as usize] {
+            seen[bytes[i] as usize] = true;
+            count += 1;
+        }
+        i += 1;
+    }
+    if count < min {
+        _Msg::new()
+            .str("concatenation contains ")
+            .usize(count)
+            .str(" distinct byte values but at least ")
+            .usize(min)
+            .str(" are required")
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_expand_tabs!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with tabs expanded to spaces.
+///
+/// This macro requires the tab width to be specified in the form
+/// `width = <usize>; ` before the expressions accepted by [`concat!`]. It works
+/// exactly like [`concat!`] except that every `\t` in the result is replaced
+/// with the number of spaces needed to reach the next column that is a
+/// multiple of `width`. Columns are counted in characters and reset after each
+/// `\n`. The macro fails to compile if `width` is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_expand_tabs;
+/// #
+/// const CONFIG: &str = "a\t= 1\n";
+/// const MORE: &str = "abcd\t= 2\n";
+/// const TEXT: &str = concat_expand_tabs!(width = 4; CONFIG, MORE);
+/// assert_eq!(TEXT, "a   = 1\nabcd    = 2\n");
+/// ```
+#[macro_export]
+macro_rules! concat_expand_tabs {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_expand_tabs::<0>(SRC, $width).len();
+        const ARR: [u8; LEN] = $crate::_expand_tabs::<LEN>(SRC, $width).finish();
+        // SAFETY: Only ASCII characters are replaced, and only with ASCII
+        // characters, so the result is still valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _expand_tabs<const N: usize>(s: &str, width: usize) -> _Buf<N> {
+    if width == 0 {
+        panic!("tab width must be greater than zero");
+    }
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut col = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if b == b'\t' {
+            let spaces = width - col % width;
+            let mut j = 0;
+            while j < spaces {
+                buf = buf.push(b' ');
+                j += 1;
+            }
+            col += spaces;
+        } else {
+            buf = buf.push(b);
+            if b == b'\n' {
+                col = 0;
+            } else if b & 0xC0 != 0x80 {
+                // Continuation bytes are part of the previous character.
+                col += 1;
+            }
+        }
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_typed!
+////////////////////////////////////////////////////////////////////////////////
+
+/// A static slice with its length in the type.
+///
+/// This is returned by [`concat_typed!`] so that generic code can rely on the
+/// length of a concatenation through the const parameter `N`. It dereferences
+/// to [`[T]`][slice], and [`ConstSlice::as_slice`] can be used in `const`
+/// contexts where [`Deref`][core::ops::Deref] is not available.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::ConstSlice;
+/// #
+/// const fn header<const N: usize>(s: ConstSlice<u8, N>) -> [u8; N] {
+///     *s.0
+/// }
+///
+/// const DATA: ConstSlice<u8, 3> = ConstSlice(&[1, 2, 3]);
+/// assert_eq!(header(DATA), [1, 2, 3]);
+/// assert_eq!(DATA.len(), 3);
+/// ```
+pub struct ConstSlice<T: 'static, const N: usize>(pub &'static [T; N]);
+
+impl<T, const N: usize> ConstSlice<T, N> {
+    /// Returns the underlying static slice.
+    pub const fn as_slice(&self) -> &'static [T] {
+        self.0
+    }
+}
+
+impl<T, const N: usize> Clone for ConstSlice<T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ConstSlice<T, N> {}
+
+impl<T: core::fmt::Debug, const N: usize> core::fmt::Debug for ConstSlice<T, N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("ConstSlice").field(&self.0).finish()
+    }
+}
+
+impl<T, const N: usize> core::ops::Deref for ConstSlice<T, N> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        self.0
+    }
+}
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a [`ConstSlice`].
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`ConstSlice<T, N>`][ConstSlice] where `N` is the length
+/// of the concatenation.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_typed, ConstSlice};
+/// #
+/// const A: &[u16] = &[1, 2];
+/// const B: &[u16] = &[3];
+/// const TABLE: ConstSlice<u16, 3> = concat_typed!([u16]: A, B);
+/// assert_eq!(*TABLE, [1, 2, 3]);
+/// ```
+#[macro_export]
+macro_rules! concat_typed {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const TYPED: $crate::ConstSlice<$T, { SRC.len() }> = $crate::ConstSlice($crate::_as_array(SRC));
+        TYPED
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _as_array<T, const N: usize>(s: &[T]) -> &[T; N] {
+    if s.len() != N {
+        panic!("invalid length");
+    }
+    // SAFETY: The slice has exactly `N` elements and `[T; N]` has the same
+    // layout as `N` contiguous elements of `T`.
+    unsafe { &*(s.as_ptr() as *const [T; N]) }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_crc16!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice with a CRC-16 appended.
+///
+/// This macro works exactly like [`concat_bytes!`] except that the CRC-16 of
+/// the concatenation is appended to it as two big-endian bytes. The CRC is
+/// computed at compile time, most significant bit first, with no reflection
+/// and no final XOR. The polynomial and initial value may be specified in the
+/// form `poly = <u16>, init = <u16>; ` before the expressions, and default to
+/// `0x1021` and `0xFFFF`, i.e. CRC-16/CCITT-FALSE. Either may be omitted.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_crc16;
+/// #
+/// const DIGITS: &[u8] = b"123456789";
+/// const FRAME: &[u8] = concat_bytes_crc16!(DIGITS);
+/// assert_eq!(FRAME[9..], [0x29, 0xB1]);
+///
+/// // CRC-16/XMODEM
+/// const XMODEM: &[u8] = concat_bytes_crc16!(poly = 0x1021, init = 0x0000; DIGITS);
+/// assert_eq!(XMODEM[9..], [0x31, 0xC3]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_crc16 {
+    (poly = $poly:expr, init = $init:expr; $($e:expr),* $(,)?) => {
+        $crate::_concat_bytes_crc16!($poly, $init; $($e),*)
+    };
+    (poly = $poly:expr; $($e:expr),* $(,)?) => {
+        $crate::_concat_bytes_crc16!($poly, 0xFFFF; $($e),*)
+    };
+    (init = $init:expr; $($e:expr),* $(,)?) => {
+        $crate::_concat_bytes_crc16!(0x1021, $init; $($e),*)
+    };
+    ($($e:expr),* $(,)?) => {
+        $crate::_concat_bytes_crc16!(0x1021, 0xFFFF; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_bytes_crc16 {
+    ($poly:expr, $init:expr; $($e:expr),*) => {{
+        const BYTES: &[u8] = $crate::concat_bytes!($($e),*);
+        const CRC: [u8; 2] = $crate::_crc16(BYTES, $poly, $init).to_be_bytes();
+        $crate::concat_slices!([u8]: BYTES, CRC)
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _crc16(src: &[u8], poly: u16, init: u16) -> u16 {
+    let mut crc = init;
+    let mut i = 0;
+    while i < src.len() {
+        crc ^= (src[i] as u16) << 8;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ poly
+            } else {
+                crc << 1
+            };
+            bit += 1;
+        }
+        i += 1;
+    }
+    crc
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_spdx!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Join `const` SPDX license identifiers into a static SPDX license
+/// expression string slice.
+///
+/// This macro takes any number of comma-separated [`&str`][str] license
+/// identifier expressions, optionally preceded by the operator in the form
+/// `op = OR; ` or `op = AND; `, and yields an expression of type
+/// [`&'static str`][str] with the identifiers joined by the operator, which
+/// defaults to `OR`.
+///
+/// The macro fails to compile if any identifier is empty or contains
+/// characters other than ASCII letters, digits, `.`, `-`, and a trailing `+`.
+/// The panic message names the index of the offending identifier.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_spdx;
+/// #
+/// const APACHE: &str = "Apache-2.0";
+/// const LICENSE: &str = concat_spdx!("MIT", APACHE);
+/// assert_eq!(LICENSE, "MIT OR Apache-2.0");
+///
+/// const BOTH: &str = concat_spdx!(op = AND; "MIT", "Zlib");
+/// assert_eq!(BOTH, "MIT AND Zlib");
+/// ```
+#[macro_export]
+macro_rules! concat_spdx {
+    (op = OR; $($e:expr),* $(,)?) => {
+        $crate::_concat_spdx!(" OR "; $($e),*)
+    };
+    (op = AND; $($e:expr),* $(,)?) => {
+        $crate::_concat_spdx!(" AND "; $($e),*)
+    };
+    ($($e:expr),* $(,)?) => {
+        $crate::_concat_spdx!(" OR "; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_spdx {
+    ($op:literal;) => {
+        ""
+    };
+    ($op:literal; $first:expr $(, $rest:expr)*) => {{
+        const _: () = $crate::_spdx_ids(&[$first $(, $rest)*]);
+        $crate::concat!($first $(, $op, $rest)*)
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _spdx_ids(ids: &[&str]) {
+    let mut i = 0;
+    while i < ids.len() {
+        let bytes = ids[i].as_bytes();
+        let mut valid = !bytes.is_empty();
+        let mut j = 0;
+        while valid && j < bytes.len() {
+            let b = bytes[j];
+            valid = b.is_ascii_alphanumeric()
+                || b == b'.'
+                || b == b'-'
+                || (b == b'+' && j > 0 && j == bytes.len() - 1);
+            j += 1;
+        }
+        if !valid {
+            _Msg::new()
+                .str("invalid SPDX license identifier at index ")
+                .usize(i)
+                .panic();
+        }
+        i += 1;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_match_glob!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice and assert that it matches a glob pattern.
+///
+/// This macro requires the pattern to be specified in the form `<&str>; `
+/// before the expressions accepted by [`concat!`]. It works exactly like
+/// [`concat!`] except that it fails to compile unless the whole result matches
+/// the pattern. The pattern supports only the following.
+///
+/// - `*` matches any sequence of characters, including the empty sequence.
+/// - `?` matches exactly one character.
+/// - Any other character matches only itself.
+///
+/// There is no escaping, no character classes, and `/` is not treated
+/// specially.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_match_glob;
+/// #
+/// const MAJOR: &str = "1";
+/// const VERSION: &str = concat_match_glob!("*.*.*"; MAJOR, ".", 42, ".", 0);
+/// assert_eq!(VERSION, "1.42.0");
+/// ```
+///
+/// The following fails to compile because there is no patch version.
+///
+/// ```compile_fail
+/// # use constcat::concat_match_glob;
+/// #
+/// const VERSION: &str = concat_match_glob!("*.*.*"; "1", ".", 42);
+/// ```
+#[macro_export]
+macro_rules! concat_match_glob {
+    ($pattern:expr; $($e:expr),* $(,)?) => {{
+        const STR: &str = $crate::_match_glob($crate::concat!($($e),*), $pattern);
+        STR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _match_glob<'a>(s: &'a str, pattern: &str) -> &'a str {
+    let sb = s.as_bytes();
+    let pb = pattern.as_bytes();
+    let mut p = 0;
+    let mut i = 0;
+    // The position of the last `*` in the pattern and where in the string it
+    // started matching, so that it can be extended if later characters fail.
+    let mut star: Option<(usize, usize)> = None;
+    while i < sb.len() {
+        if p < pb.len() && pb[p] == b'?' {
+            i += _decode_utf8(sb, i).1;
+            p += 1;
+        } else if p < pb.len() && pb[p] == b'*' {
+            star = Some((p, i));
+            p += 1;
+        } else if p < pb.len() && pb[p] == sb[i] {
+            i += 1;
+            p += 1;
+        } else if let Some((star_p, star_i)) = star {
+            let next = star_i + _decode_utf8(sb, star_i).1;
+            star = Some((star_p, next));
+            p = star_p + 1;
+            i = next;
+        } else {
+            panic!("concatenation does not match the glob pattern");
+        }
+    }
+    while p < pb.len() && pb[p] == b'*' {
+        p += 1;
+    }
+    if p != pb.len() {
+        panic!("concatenation does not match the glob pattern");
+    }
+    s
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_uuid!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and format
+/// the result as a static UUID string slice.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static str`][str] containing the concatenation
+/// formatted in the canonical hyphenated form, i.e. lowercase hexadecimal in
+/// groups of 8, 4, 4, 4, and 12 digits. The macro fails to compile if the
+/// concatenation is not exactly 16 bytes.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_uuid;
+/// #
+/// const TIME: &[u8] = &[0x6b, 0xa7, 0xb8, 0x10, 0x9d, 0xad, 0x11, 0xd1];
+/// const NODE: &[u8] = &[0x80, 0xb4, 0x00, 0xc0, 0x4f, 0xd4, 0x30, 0xc8];
+/// const NAMESPACE_DNS: &str = concat_uuid!(TIME, NODE);
+/// assert_eq!(NAMESPACE_DNS, "6ba7b810-9dad-11d1-80b4-00c04fd430c8");
+/// ```
+#[macro_export]
+macro_rules! concat_uuid {
+    ($($e:expr),* $(,)?) => {{
+        const ARR: [u8; 36] = $crate::_uuid($crate::concat_bytes!($($e),*)).finish();
+        // SAFETY: The array only contains ASCII hexadecimal digits and `-`.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _uuid(bytes: &[u8]) -> _Buf<36> {
+    if bytes.len() != 16 {
+        _Msg::new()
+            .str("a UUID must be 16 bytes but the concatenation is ")
+            .usize(bytes.len())
+            .str(" bytes")
+            .panic();
+    }
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < 16 {
+        if matches!(i, 4 | 6 | 8 | 10) {
+            buf = buf.push(b'-');
+        }
+        buf = buf.hex_lower(bytes[i]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_freq_order!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and return
+/// the distinct byte values ordered by frequency.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static [u8]`][slice] containing each byte value
+/// that occurs in the concatenation exactly once, ordered from the most to the
+/// least frequent. Byte values that occur equally often are ordered by
+/// ascending value.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_freq_order;
+/// #
+/// const TEXT: &[u8] = b"mississippi";
+/// const MORE: &[u8] = b"ss";
+/// const ALPHABET: &[u8] = concat_freq_order!(TEXT, MORE);
+/// assert_eq!(ALPHABET, b"sipm");
+/// ```
+#[macro_export]
+macro_rules! concat_freq_order {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_freq_order::<0>(SRC).len();
+        const ARR: [u8; LEN] = $crate::_freq_order::<LEN>(SRC).finish();
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _freq_order<const N: usize>(src: &[u8]) -> _Buf<N> {
+    let mut counts = [0usize; 256];
+    let mut i = 0;
+    while i < src.len() {
+        counts[src[i] as usize] += 1;
+        i += 1;
+    }
+    // Insertion sort the present values, which are visited in ascending order,
+    // so that equally frequent values stay in ascending order.
+    let mut order = [0u8; 256];
+    let mut len = 0;
+    let mut b = 0;
+    while b < 256 {
+        if counts[b] > 0 {
+            let mut j = len;
+            while j > 0 && counts[order[j - 1] as usize] < counts[b] {
+                order[j] = order[j - 1];
+                j -= 1;
+            }
+            order[j] = b as u8;
+            len += 1;
+        }
+        b += 1;
+    }
+    _Buf::new().bytes(_subslice(&order, 0, len))
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_wrap_words!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with lines wrapped at a width.
+///
+/// This macro requires the maximum width to be specified in the form
+/// `width = <usize>; ` before the expressions accepted by [`concat!`]. The
+/// operands are concatenated and then words are greedily wrapped so that no
+/// line is longer than `width` bytes, by replacing the ASCII space before a
+/// word that does not fit with a `\n`. Existing line breaks are kept. Words are
+/// never broken, so a word longer than `width` is placed on its own line and
+/// that line overflows the width.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_wrap_words;
+/// #
+/// const HELP: &str = "Print this help message ";
+/// const TEXT: &str = concat_wrap_words!(width = 16; HELP, "and exit.");
+/// assert_eq!(TEXT, "Print this help\nmessage and\nexit.");
+/// ```
+#[macro_export]
+macro_rules! concat_wrap_words {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_wrap_words::<0>(SRC, $width).len();
+        const ARR: [u8; LEN] = $crate::_wrap_words::<LEN>(SRC, $width).finish();
+        // SAFETY: Only ASCII spaces are replaced, and only with ASCII newlines,
+        // so the result is still valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _wrap_words<const N: usize>(s: &str, width: usize) -> _Buf<N> {
+    let bytes = s.as_bytes();
+    let mut buf = _Buf::new();
+    let mut col = 0;
+    let mut first = true;
+    let mut i = 0;
+    while i <= bytes.len() {
+        let mut end = i;
+        while end < bytes.len() && bytes[end] != b' ' && bytes[end] != b'\n' {
+            end += 1;
+        }
+        let word = end - i;
+        if !first {
+            if col + 1 + word > width {
+                buf = buf.push(b'\n');
+                col = 0;
+            } else {
+                buf = buf.push(b' ');
+                col += 1;
+            }
+        }
+        buf = buf.bytes(_subslice(bytes, i, end));
+        col += word;
+        first = false;
+        if end < bytes.len() && bytes[end] == b'\n' {
+            buf = buf.push(b'\n');
+            col = 0;
+            first = true;
+        }
+        i = end + 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_gen!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Generate a static slice by calling a `const fn` over a range of indices.
+///
+/// This macro requires the type of slice to be specified in the form `[T]: `
+/// followed by a `usize` range expression, `=>`, and the path to a `const fn`
+/// that takes a `usize`. The function is called for each index in the range in
+/// order and the results are concatenated into an expression of type
+/// [`&'static [T]`][slice].
+///
+/// By default the function must return a `T`. If the path is preceded by
+/// `flatten` the function must instead return a [`&[T]`][slice] or `[T; N]`,
+/// which may have a different length for each index.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_gen;
+/// #
+/// const fn square(i: usize) -> u8 {
+///     (i * i) as u8
+/// }
+/// const SQUARES: &[u8] = concat_gen!([u8]: 0..6 => square);
+/// assert_eq!(SQUARES, [0, 1, 4, 9, 16, 25]);
+///
+/// const fn repeat(i: usize) -> &'static [u8] {
+///     const TABLE: [&[u8]; 3] = [&[], &[1], &[2, 2]];
+///     TABLE[i]
+/// }
+/// const REPEATED: &[u8] = concat_gen!([u8]: 0..3 => flatten repeat);
+/// assert_eq!(REPEATED, [1, 2, 2]);
+/// ```
+#[macro_export]
+macro_rules! concat_gen {
+    ([$T:ty]: $range:expr => flatten $f:path) => {{
+        const RANGE: $crate::core::ops::Range<usize> = $range;
+        const LEN: usize = {
+            let mut len = 0;
+            let mut i = RANGE.start;
+            while i < RANGE.end {
+                len += $f(i).len();
+                i += 1;
+            }
+            len
+        };
+        const ARR: [$T; LEN] = {
+            // The part for index `idx` and the offset of its first element. The
+            // function is called once per index, the dead branch only names
+            // its return type.
+            let mut idx = RANGE.start;
+            let mut part = if false { Some($f(idx)) } else { None };
+            let mut base = 0;
+            $crate::_from_fn!([$T; LEN], |i| loop {
+                if let Some(part) = &part {
+                    if i - base < part.len() {
+                        break part[i - base];
+                    }
+                    base += part.len();
+                    idx += 1;
+                }
+                part = Some($f(idx));
+            })
+        };
+        &ARR
+    }};
+
+    ([$T:ty]: $range:expr => $f:path) => {{
+        const RANGE: $crate::core::ops::Range<usize> = $range;
+        const LEN: usize = RANGE.end.saturating_sub(RANGE.start);
+        const ARR: [$T; LEN] = $crate::_from_fn!([$T; LEN], |i| $f(RANGE.start + i));
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_roundtrip!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that it round-trips through an encoder and decoder.
+///
+/// This macro requires the paths to an encoding and a decoding `const fn` to
+/// be specified in the form `encode, decode; ` before the expressions accepted
+/// by [`concat_bytes!`]. Both functions take a [`&[u8]`][slice] and return a
+/// [`&[u8]`][slice] or `[u8; N]`. The macro works exactly like
+/// [`concat_bytes!`] except that it fails to compile unless decoding the
+/// encoded concatenation yields the concatenation again. The panic message
+/// names the first index at which the round-trip differs.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_roundtrip;
+/// #
+/// const fn swap_nibbles(src: &[u8]) -> [u8; 4] {
+///     let mut out = [0; 4];
+///     let mut i = 0;
+///     while i < out.len() {
+///         out[i] = src[i].rotate_left(4);
+///         i += 1;
+///     }
+///     out
+/// }
+///
+/// const A: &[u8] = &[0x12, 0x34];
+/// const B: &[u8] = &[0x56, 0x78];
+/// const DATA: &[u8] = concat_roundtrip!(swap_nibbles, swap_nibbles; A, B);
+/// assert_eq!(DATA, [0x12, 0x34, 0x56, 0x78]);
+/// ```
+#[macro_export]
+macro_rules! concat_roundtrip {
+    ($encode:path, $decode:path; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_roundtrip(ARR, &$decode(&$encode(ARR)));
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _roundtrip(original: &[u8], decoded: &[u8]) {
+    let mut i = 0;
+    while i < original.len() && i < decoded.len() {
+        if original[i] != decoded[i] {
+            _Msg::new()
+                .str("round-trip differs at index ")
+                .usize(i)
+                .str(": expected 0x")
+                .hex_lower(original[i])
+                .str(", found 0x")
+                .hex_lower(decoded[i])
+                .panic();
+        }
+        i += 1;
+    }
+    if original.len() != decoded.len() {
+        _Msg::new()
+            .str("round-trip changes the length from ")
+            .usize(original.len())
+            .str(" to ")
+            .usize(decoded.len())
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_prefix_sums!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices and return the running sums of the
+/// elements.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields a static
+/// slice of type `&'static [T]` with the same length as the concatenation,
+/// where the element at index `i` is the sum of the elements at indices `0`
+/// through `i`. The macro fails to compile if a sum overflows `T`. The panic
+/// message names the index of the element at which it overflowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_prefix_sums;
+/// #
+/// const A: &[u32] = &[3, 1];
+/// const B: &[u32] = &[4, 1, 5];
+/// const SUMS: &[u32] = concat_prefix_sums!([u32]: A, B);
+/// assert_eq!(SUMS, [3, 4, 8, 9, 14]);
+/// ```
+///
+/// The following fails to compile because the running sum overflows `u8`.
+///
+/// ```compile_fail
+/// # use constcat::concat_prefix_sums;
+/// #
+/// const SUMS: &[u8] = concat_prefix_sums!([u8]: &[200], &[100]);
+/// ```
+#[macro_export]
+macro_rules! concat_prefix_sums {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const ARR: [$T; SRC.len()] = {
+            let mut arr: [$T; SRC.len()] = [0; SRC.len()];
+            let mut acc: $T = 0;
+            let mut i = 0;
+            while i < SRC.len() {
+                acc = match acc.checked_add(SRC[i]) {
+                    Some(acc) => acc,
+                    None => $crate::_Msg::new()
+                        .str("the prefix sum overflows at index ")
+                        .usize(i)
+                        .panic(),
+                };
+                arr[i] = acc;
+                i += 1;
+            }
+            arr
+        };
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_numbered!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice formatted as a numbered list.
+///
+/// This macro takes any number of comma-separated [`&str`][str] literals or
+/// constant expressions and yields an expression of type
+/// [`&'static str`][str] in which each operand is prefixed with its number and
+/// a separator, and the numbered operands are joined by a delimiter. The
+/// numbering, separator and delimiter may be configured in the form
+/// `start = <usize>, sep = <&str>, join = <&str>; ` before the expressions,
+/// in that order, and default to `1`, `". "` and `"\n"`. Any of them may be
+/// omitted.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_numbered;
+/// #
+/// const HELP: &str = concat_numbered!("Start", "Options", "Quit");
+/// assert_eq!(HELP, "1. Start\n2. Options\n3. Quit");
+///
+/// const STEPS: &str = concat_numbered!(start = 0, sep = ") ", join = "; "; "fetch", "build");
+/// assert_eq!(STEPS, "0) fetch; 1) build");
+/// ```
+#[macro_export]
+macro_rules! concat_numbered {
+    ($($tt:tt)*) => {
+        $crate::_concat_numbered!([1, ". ", "\n"] $($tt)*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_numbered {
+    ([$start:expr, $sep:expr, $join:expr] start = $v:expr, $($rest:tt)*) => {
+        $crate::_concat_numbered!([$v, $sep, $join] $($rest)*)
+    };
+    ([$start:expr, $sep:expr, $join:expr] start = $v:expr; $($rest:tt)*) => {
+        $crate::_concat_numbered!([$v, $sep, $join] ; $($rest)*)
+    };
+    ([$start:expr, $sep:expr, $join:expr] sep = $v:expr, $($rest:tt)*) => {
+        $crate::_concat_numbered!([$start, $v, $join] $($rest)*)
+    };
+    ([$start:expr, $sep:expr, $join:expr] sep = $v:expr; $($rest:tt)*) => {
+        $crate::_concat_numbered!([$start, $v, $join] ; $($rest)*)
+    };
+    ([$start:expr, $sep:expr, $join:expr] join = $v:expr; $($rest:tt)*) => {
+        $crate::_concat_numbered!([$start, $sep, $v] ; $($rest)*)
+    };
+    ([$start:expr, $sep:expr, $join:expr] $(;)? $($e:expr),* $(,)?) => {{
+        const STRS: &[&str] = &[$($e),*];
+        const LEN: usize = $crate::_numbered::<0>(STRS, $start, $sep, $join).len();
+        const ARR: [u8; LEN] = $crate::_numbered::<LEN>(STRS, $start, $sep, $join).finish();
+        // SAFETY: The array only contains ASCII digits and the bytes of valid
+        // string slices so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _numbered<const N: usize>(
+    strs: &[&str],
+    start: usize,
+    sep: &str,
+    join: &str,
+) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < strs.len() {
+        if i > 0 {
+            buf = buf.str(join);
+        }
+        buf = buf.usize(start + i).str(sep).str(strs[i]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_require_subsequence!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// byte slice and assert that the result contains a subsequence.
+///
+/// This macro takes a `const` [`&[u8]`][slice] needle followed by a semicolon
+/// and then any number of comma-separated expressions as accepted by
+/// [`concat_bytes!`]. It works exactly like [`concat_bytes!`] except that it
+/// fails to compile unless the bytes of the needle all occur in the result in
+/// the same order, though not necessarily next to each other. The panic
+/// message names the index of the first needle byte that could not be
+/// matched.
+///
+/// See [`concat_bytes_require_contains!`] to require a contiguous subslice.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_require_subsequence;
+/// #
+/// const ORDER: &[u8] = &[0x01, 0x02, 0x03];
+/// const HELLO: &[u8] = &[0x01, 0xAA];
+/// const BODY: &[u8] = &[0x02, 0xBB, 0x03];
+/// const SESSION: &[u8] = concat_require_subsequence!(ORDER; HELLO, BODY);
+/// ```
+///
+/// The following fails to compile because the messages are out of order.
+///
+/// ```compile_fail
+/// # use constcat::concat_require_subsequence;
+/// #
+/// const ORDER: &[u8] = &[0x01, 0x02, 0x03];
+/// const SESSION: &[u8] = concat_require_subsequence!(ORDER; &[0x02, 0x01], &[0x03]);
+/// ```
+#[macro_export]
+macro_rules! concat_require_subsequence {
+    ($needle:expr; $($e:expr),* $(,)?) => {{
+        const ARR: &[u8] = $crate::concat_bytes!($($e),*);
+        const _: () = $crate::_require_subsequence(ARR, $crate::concat_bytes!($needle));
+        ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _require_subsequence(haystack: &[u8], needle: &[u8]) {
+    let mut i = 0;
+    let mut j = 0;
+    while i < haystack.len() && j < needle.len() {
+        if haystack[i] == needle[j] {
+            j += 1;
+        }
+        i += 1;
+    }
+    if j < needle.len() {
+        _Msg::new()
+            .str("concatenation does not contain the required subsequence, needle index ")
+            .usize(j)
+            .str(" is unmatched")
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_encode_table!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and encode
+/// each byte of the result using a lookup table.
+///
+/// This macro requires a `const` table of type `&[&str; 256]` to be specified
+/// in the form `table = <table>; ` before the expressions accepted by
+/// [`concat_bytes!`]. It yields an expression of type [`&'static str`][str]
+/// which is the table entry for each byte of the concatenation, in order. A
+/// separator may be specified in the form `table = <table>, sep = <&str>; `
+/// and is inserted between consecutive entries, it defaults to the empty
+/// string.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_encode_table;
+/// #
+/// const MORSE: &[&str; 256] = &{
+///     let mut table = [""; 256];
+///     table[b'E' as usize] = ".";
+///     table[b'O' as usize] = "---";
+///     table[b'S' as usize] = "...";
+///     table
+/// };
+/// const SOS: &[u8] = b"SOS";
+/// const CODE: &str = concat_encode_table!(table = MORSE, sep = " "; SOS);
+/// assert_eq!(CODE, "... --- ...");
+/// ```
+#[macro_export]
+macro_rules! concat_encode_table {
+    (table = $table:expr, sep = $sep:expr; $($e:expr),* $(,)?) => {
+        $crate::_concat_encode_table!($table, $sep; $($e),*)
+    };
+    (table = $table:expr; $($e:expr),* $(,)?) => {
+        $crate::_concat_encode_table!($table, ""; $($e),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_encode_table {
+    ($table:expr, $sep:expr; $($e:expr),*) => {{
+        const TABLE: &[&str; 256] = $table;
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = $crate::_encode_table::<0>(SRC, TABLE, $sep).len();
+        const ARR: [u8; LEN] = $crate::_encode_table::<LEN>(SRC, TABLE, $sep).finish();
+        // SAFETY: The array only contains the bytes of valid string slices so it
+        // is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _encode_table<const N: usize>(src: &[u8], table: &[&str; 256], sep: &str) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        if i > 0 {
+            buf = buf.str(sep);
+        }
+        buf = buf.str(table[src[i] as usize]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bytes_xorfold!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and fold the
+/// result into a fixed width fingerprint by XOR.
+///
+/// This macro requires the width to be specified in the form
+/// `width = <usize>; ` before the expressions accepted by [`concat_bytes!`].
+/// It yields an expression of type `&'static [u8; width]`. The concatenation
+/// is split into consecutive chunks of `width` bytes starting at index zero and
+/// the chunks are XORed together, so byte `i` of the fingerprint is the XOR of
+/// every byte of the concatenation whose index modulo `width` is `i`. The last
+/// chunk is padded with zero bytes if it is short, which leaves the
+/// corresponding fingerprint bytes unchanged, and an empty concatenation folds
+/// to all zeros. The macro fails to compile if `width` is zero.
+///
+/// This is not a cryptographic hash or even a good checksum, for example
+/// swapping two chunks does not change the fingerprint.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bytes_xorfold;
+/// #
+/// const A: &[u8] = &[0x01, 0x02, 0x03, 0x04];
+/// const B: &[u8] = &[0x10, 0x20];
+/// const PRINT: &[u8; 4] = concat_bytes_xorfold!(width = 4; A, B);
+/// assert_eq!(PRINT, &[0x11, 0x22, 0x03, 0x04]);
+/// ```
+#[macro_export]
+macro_rules! concat_bytes_xorfold {
+    (width = $width:expr; $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const ARR: [u8; $width] = $crate::_xorfold(SRC);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _xorfold<const W: usize>(src: &[u8]) -> [u8; W] {
+    if W == 0 {
+        panic!("`width` must be greater than zero");
+    }
+    let mut out = [0; W];
+    let mut i = 0;
+    while i < src.len() {
+        out[i % W] ^= src[i];
+        i += 1;
+    }
+    out
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_colored! and concat_strip_ansi!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with each operand wrapped in an ANSI color code.
+///
+/// This macro takes any number of comma-separated `(<expr>, <u8>)` pairs,
+/// where the expression is anything accepted by [`concat!`] and the integer is
+/// an ANSI SGR parameter. It yields an expression of type
+/// [`&'static str`][str] in which each operand is preceded by the escape
+/// sequence `ESC [ <code> m` and followed by the reset sequence `ESC [ 0 m`.
+///
+/// Use [`concat_strip_ansi!`] to get the plain text back, for example when the
+/// output is not a terminal.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_colored;
+/// #
+/// const STATUS: &str = "ok";
+/// const LINE: &str = concat_colored!(("error: ", 31), (STATUS, 32));
+/// assert_eq!(LINE, "\x1b[31merror: \x1b[0m\x1b[32mok\x1b[0m");
+/// ```
+#[macro_export]
+macro_rules! concat_colored {
+    ($(($e:expr, $code:expr)),* $(,)?) => {{
+        const STRS: &[&str] = &[$($crate::concat!($e)),*];
+        const CODES: &[u8] = &[$($code),*];
+        const LEN: usize = $crate::_colored::<0>(STRS, CODES).len();
+        const ARR: [u8; LEN] = $crate::_colored::<LEN>(STRS, CODES).finish();
+        // SAFETY: The array only contains ASCII and the bytes of valid string
+        // slices so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice with ANSI escape sequences removed.
+///
+/// This macro works exactly like [`concat!`] except that every control
+/// sequence of the form `ESC [ <parameters> <final byte>` is removed from the
+/// result, which is the form of all of the sequences produced by
+/// [`concat_colored!`]. Any other text, including escape characters that do
+/// not start a complete control sequence, is kept as is.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_colored, concat_strip_ansi};
+/// #
+/// const LINE: &str = concat_colored!(("error: ", 31), ("disk full", 1));
+/// const PLAIN: &str = concat_strip_ansi!(LINE);
+/// assert_eq!(PLAIN, "error: disk full");
+/// ```
+#[macro_export]
+macro_rules! concat_strip_ansi {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_strip_ansi::<0>(SRC.as_bytes()).len();
+        const ARR: [u8; LEN] = $crate::_strip_ansi::<LEN>(SRC.as_bytes()).finish();
+        // SAFETY: Only whole ASCII control sequences are removed from a valid
+        // string slice so the array is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _colored<const N: usize>(strs: &[&str], codes: &[u8]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < strs.len() {
+        buf = buf
+            .str("\x1b[")
+            .usize(codes[i] as usize)
+            .push(b'm')
+            .str(strs[i])
+            .str("\x1b[0m");
+        i += 1;
+    }
+    buf
+}
+
+#[doc(hidden)]
+pub const fn _strip_ansi<const N: usize>(src: &[u8]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        if src[i] == 0x1B && i + 1 < src.len() && src[i + 1] == b'[' {
+            // Parameter and intermediate bytes followed by one final byte.
+            let mut j = i + 2;
+            while j < src.len() && src[j] >= 0x20 && src[j] <= 0x3F {
+                j += 1;
+            }
+            if j < src.len() && src[j] >= 0x40 && src[j] <= 0x7E {
+                i = j + 1;
+                continue;
+            }
+        }
+        buf = buf.push(src[i]);
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// dot!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Compute the dot product of two `const` integer slices.
+///
+/// This macro takes an element type in the form `[T]: ` followed by two
+/// comma-separated `const` [`&[T]`][slice] expressions and yields an
+/// expression of type `T` which is the sum of the products of the elements at
+/// the same index, or zero if the slices are empty. The macro fails to compile
+/// if the slices have different lengths, or if a product or the sum overflows
+/// `T`. The panic message names the index at which it overflowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::dot;
+/// #
+/// const COEFFS: &[i32] = &[1, -2, 3];
+/// const SAMPLES: &[i32] = &[4, 5, 6];
+/// const GAIN: i32 = dot!([i32]: COEFFS, SAMPLES);
+/// assert_eq!(GAIN, 12);
+/// ```
+///
+/// The following fails to compile because the slices have different lengths.
+///
+/// ```compile_fail
+/// # use constcat::dot;
+/// #
+/// const GAIN: i32 = dot!([i32]: &[1, 2], &[3]);
+/// ```
+#[macro_export]
+macro_rules! dot {
+    ([$T:ty]: $a:expr, $b:expr $(,)?) => {{
+        const ACC: $T = {
+            let a: &[$T] = $a;
+            let b: &[$T] = $b;
+            if a.len() != b.len() {
+                $crate::_Msg::new()
+                    .str("cannot compute the dot product of slices with lengths ")
+                    .usize(a.len())
+                    .str(" and ")
+                    .usize(b.len())
+                    .panic();
+            }
+            let mut acc: $T = 0;
+            let mut i = 0;
+            while i < a.len() {
+                acc = match a[i].checked_mul(b[i]) {
+                    Some(p) => match acc.checked_add(p) {
+                        Some(acc) => acc,
+                        None => $crate::_Msg::new()
+                            .str("the dot product overflows at index ")
+                            .usize(i)
+                            .panic(),
+                    },
+                    None => $crate::_Msg::new()
+                        .str("the dot product overflows at index ")
+                        .usize(i)
+                        .panic(),
+                };
+                i += 1;
+            }
+            acc
+        };
+        ACC
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_ipv4! and concat_ipv6!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Format four `const` octets as a static IPv4 address string slice.
+///
+/// This macro takes exactly four comma-separated integer literals or constant
+/// expressions and yields an expression of type [`&'static str`][str] with
+/// the octets in dotted-decimal notation. The macro fails to compile if an
+/// octet is negative or greater than 255. The panic message names the index of
+/// the first invalid octet.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_ipv4;
+/// #
+/// const SUBNET: u8 = 1;
+/// const HOST: i32 = 20;
+/// const ADDR: &str = concat_ipv4!(192, 168, SUBNET, HOST);
+/// assert_eq!(ADDR, "192.168.1.20");
+/// ```
+///
+/// The following fails to compile because the last octet is out of range.
+///
+/// ```compile_fail
+/// # use constcat::concat_ipv4;
+/// #
+/// const ADDR: &str = concat_ipv4!(10, 0, 0, 256);
+/// ```
+#[macro_export]
+macro_rules! concat_ipv4 {
+    ($a:expr, $b:expr, $c:expr, $d:expr $(,)?) => {{
+        const INTS: &[(bool, u128)] = &[
+            $crate::_int!($a),
+            $crate::_int!($b),
+            $crate::_int!($c),
+            $crate::_int!($d),
+        ];
+        const _: () = $crate::_ipv4_octets(INTS);
+        const LEN: usize = $crate::_int_csv::<0>(INTS, ".").len();
+        const ARR: [u8; LEN] = $crate::_int_csv::<LEN>(INTS, ".").finish();
+        // SAFETY: The array only contains ASCII digits and `.` so it is valid
+        // UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+/// Format eight `const` groups as a static IPv6 address string slice.
+///
+/// This macro takes exactly eight comma-separated `u16` literals or constant
+/// expressions and yields an expression of type [`&'static str`][str] with
+/// the address in the canonical text form of [RFC 5952]. That is, each group
+/// is written in lowercase hexadecimal without leading zeros and separated by
+/// `:`, except that the longest run of two or more zero groups is replaced by
+/// `::`. If there are several longest runs the first one is replaced.
+///
+/// [RFC 5952]: https://www.rfc-editor.org/rfc/rfc5952
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_ipv6;
+/// #
+/// const PREFIX: u16 = 0x2001;
+/// const ADDR: &str = concat_ipv6!(PREFIX, 0xDB8, 0, 0, 0, 0, 0, 1);
+/// assert_eq!(ADDR, "2001:db8::1");
+/// ```
+#[macro_export]
+macro_rules! concat_ipv6 {
+    (
+        $a:expr, $b:expr, $c:expr, $d:expr,
+        $e:expr, $f:expr, $g:expr, $h:expr $(,)?
+    ) => {{
+        const GROUPS: [u16; 8] = [$a, $b, $c, $d, $e, $f, $g, $h];
+        const LEN: usize = $crate::_ipv6::<0>(GROUPS).len();
+        const ARR: [u8; LEN] = $crate::_ipv6::<LEN>(GROUPS).finish();
+        // SAFETY: The array only contains ASCII hexadecimal digits and `:` so
+        // it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _ipv4_octets(ints: &[(bool, u128)]) {
+    let mut i = 0;
+    while i < ints.len() {
+        let (neg, abs) = ints[i];
+        if neg || abs > 255 {
+            _Msg::new()
+                .str("IPv4 octet at index ")
+                .usize(i)
+                .str(" is out of range: ")
+                .int(ints[i])
+                .panic();
+        }
+        i += 1;
+    }
+}
+
+#[doc(hidden)]
+pub const fn _ipv6<const N: usize>(groups: [u16; 8]) -> _Buf<N> {
+    // Find the first longest run of at least two zero groups.
+    let mut run_start = 8;
+    let mut run_len = 1;
+    let mut i = 0;
+    while i < 8 {
+        let mut j = i;
+        while j < 8 && groups[j] == 0 {
+            j += 1;
+        }
+        if j - i > run_len {
+            run_start = i;
+            run_len = j - i;
+        }
+        i = if j > i { j } else { i + 1 };
+    }
+
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < 8 {
+        if i == run_start {
+            buf = buf.str("::");
+            i += run_len;
+            continue;
+        }
+        if i > 0 && i != run_start + run_len {
+            buf = buf.push(b':');
+        }
+        buf = _ipv6_group(buf, groups[i]);
+        i += 1;
+    }
+    buf
+}
+
+const fn _ipv6_group<const N: usize>(mut buf: _Buf<N>, group: u16) -> _Buf<N> {
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut shift = 12;
+    while shift > 0 && (group >> shift) == 0 {
+        shift -= 4;
+    }
+    loop {
+        buf = buf.push(DIGITS[((group >> shift) & 0xF) as usize]);
+        if shift == 0 {
+            return buf;
+        }
+        shift -= 4;
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// assert_semver_ge!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Assert that a `const` version string is greater than or equal to another.
+///
+/// This macro takes two constant [`&str`][str] expressions, such as the output
+/// of [`concat!`], each of which is a version made up of one or more decimal
+/// numbers separated by `.`, for example `1.2.0`. It fails to compile unless
+/// the first version is greater than or equal to the second. Versions are
+/// compared component by component from the left and missing components are
+/// treated as zero, so `1.2` is equal to `1.2.0`. Pre-release and build
+/// metadata suffixes are not supported and, like any other malformed version,
+/// fail to compile.
+///
+/// This macro can be used anywhere an item or statement is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{assert_semver_ge, concat};
+/// #
+/// const MAJOR: &str = "1";
+/// const MINOR: &str = "10";
+/// assert_semver_ge!(concat!(MAJOR, ".", MINOR), "1.2.0");
+/// ```
+///
+/// The following fails to compile because the version is too old.
+///
+/// ```compile_fail
+/// # use constcat::assert_semver_ge;
+/// #
+/// assert_semver_ge!("1.1.9", "1.2");
+/// ```
+#[macro_export]
+macro_rules! assert_semver_ge {
+    ($version:expr, $min:expr $(,)?) => {
+        const _: () = $crate::_semver_ge($version, $min);
+    };
+}
+
+#[doc(hidden)]
+pub const fn _semver_ge(version: &str, min: &str) {
+    let a = version.as_bytes();
+    let b = min.as_bytes();
+    _semver_validate(a);
+    _semver_validate(b);
+    let mut i = 0;
+    let mut j = 0;
+    while i < a.len() || j < b.len() {
+        let (x, next_i) = _semver_component(a, i);
+        let (y, next_j) = _semver_component(b, j);
+        if x > y {
+            return;
+        }
+        if x < y {
+            _Msg::new()
+                .str("version ")
+                .str(version)
+                .str(" is less than the required ")
+                .str(min)
+                .panic();
+        }
+        i = next_i;
+        j = next_j;
+    }
+}
+
+/// Panics unless `bytes` is one or more decimal numbers separated by `.`.
+const fn _semver_validate(bytes: &[u8]) {
+    let mut digits = 0;
+    let mut i = 0;
+    while i <= bytes.len() {
+        if i == bytes.len() || bytes[i] == b'.' {
+            if digits == 0 {
+                _Msg::new()
+                    .str("invalid version `")
+                    .bytes(bytes)
+                    .str("`, expected numbers separated by `.`")
+                    .panic();
+            }
+            digits = 0;
+        } else if bytes[i].is_ascii_digit() {
+            digits += 1;
+        } else {
+            _Msg::new()
+                .str("invalid version `")
+                .bytes(bytes)
+                .str("`, unexpected character at byte index ")
+                .usize(i)
+                .panic();
+        }
+        i += 1;
+    }
+}
+
+/// Parses the component starting at `i` and returns it along with the index of
+/// the next component, or zero if there are no components left.
+const fn _semver_component(bytes: &[u8], mut i: usize) -> (u64, usize) {
+    let mut n: u64 = 0;
+    while i < bytes.len() && bytes[i] != b'.' {
+        n = match n.checked_mul(10) {
+            Some(n) => match n.checked_add((bytes[i] - b'0') as u64) {
+                Some(n) => n,
+                None => panic!("version component overflows `u64`"),
+            },
+            None => panic!("version component overflows `u64`"),
+        };
+        i += 1;
+    }
+    if i < bytes.len() {
+        i += 1;
+    }
+    (n, i)
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_bitrev! and concat_graycode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Generate a static table of bit-reversed indices.
+///
+/// This macro requires the number of bits to be specified in the form
+/// `bits = <u32>`. It yields an expression of type `&'static [u16]` with
+/// `2^bits` elements, where the element at index `i` is `i` with its lowest
+/// `bits` bits in reverse order. This is the permutation used to reorder the
+/// input of a radix-2 FFT. The macro fails to compile if `bits` is greater
+/// than 16.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_bitrev;
+/// #
+/// const ORDER: &[u16] = concat_bitrev!(bits = 3);
+/// assert_eq!(ORDER, [0, 4, 2, 6, 1, 5, 3, 7]);
+/// ```
+#[macro_export]
+macro_rules! concat_bitrev {
+    (bits = $bits:expr $(,)?) => {{
+        const BITS: u32 = $bits;
+        const LEN: usize = $crate::_index_table_len(BITS);
+        const ARR: [u16; LEN] = $crate::_from_fn!([u16; LEN], |i| $crate::_bitrev(i as u16, BITS));
+        &ARR
+    }};
+}
+
+/// Generate a static table of Gray codes.
+///
+/// This macro requires the number of bits to be specified in the form
+/// `bits = <u32>`. It yields an expression of type `&'static [u16]` with
+/// `2^bits` elements, where the element at index `i` is the binary reflected
+/// Gray code of `i`, i.e. `i ^ (i >> 1)`. Consecutive elements differ in
+/// exactly one bit. The macro fails to compile if `bits` is greater than 16.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_graycode;
+/// #
+/// const CODES: &[u16] = concat_graycode!(bits = 2);
+/// assert_eq!(CODES, [0b00, 0b01, 0b11, 0b10]);
+/// ```
+#[macro_export]
+macro_rules! concat_graycode {
+    (bits = $bits:expr $(,)?) => {{
+        const LEN: usize = $crate::_index_table_len($bits);
+        const ARR: [u16; LEN] = $crate::_from_fn!([u16; LEN], |i| (i ^ (i >> 1)) as u16);
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _index_table_len(bits: u32) -> usize {
+    if bits > 16 {
+        _Msg::new()
+            .str("`bits` must be at most 16, found ")
+            .usize(bits as usize)
+            .panic();
+    }
+    1 << bits
+}
+
+#[doc(hidden)]
+pub const fn _bitrev(i: u16, bits: u32) -> u16 {
+    match i.reverse_bits().checked_shr(16 - bits) {
+        Some(rev) => rev,
+        None => 0,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// assert_checksum!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Assert that a `const` byte slice expression contains a correct checksum
+/// byte.
+///
+/// This macro takes a constant [`&[u8]`][slice] expression, such as the output
+/// of [`concat_bytes!`] or [`include_bytes!`], followed by the index of the
+/// checksum byte and the algorithm in the form `index = <usize>, algo = <algo>`.
+/// It computes the checksum of every byte of the slice except the one at the
+/// index and fails to compile unless it is equal to that byte, or if the index
+/// is out of bounds. The following algorithms are supported.
+///
+/// - `xor`: the XOR of the bytes.
+/// - `sum`: the sum of the bytes modulo 256.
+///
+/// This macro can be used anywhere an item or statement is allowed.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::assert_checksum;
+/// #
+/// const IMAGE: &[u8] = &[0x12, 0x34, 0x26, 0x00];
+/// assert_checksum!(IMAGE, index = 2, algo = xor);
+/// assert_checksum!(&[0xFF, 0x02, 0x03], index = 1, algo = sum);
+/// ```
+///
+/// The following fails to compile because the checksum is stale.
+///
+/// ```compile_fail
+/// # use constcat::assert_checksum;
+/// #
+/// assert_checksum!(&[0x12, 0x34, 0x00], index = 2, algo = xor);
+/// ```
+///
+/// [`include_bytes!`]: core::include_bytes
+#[macro_export]
+macro_rules! assert_checksum {
+    ($e:expr, index = $index:expr, algo = xor $(,)?) => {
+        const _: () = $crate::_assert_checksum($e, $index, false);
+    };
+    ($e:expr, index = $index:expr, algo = sum $(,)?) => {
+        const _: () = $crate::_assert_checksum($e, $index, true);
+    };
+}
+
+#[doc(hidden)]
+pub const fn _assert_checksum(blob: &[u8], index: usize, sum: bool) {
+    if index >= blob.len() {
+        _Msg::new()
+            .str("checksum index ")
+            .usize(index)
+            .str(" is out of bounds for a blob of length ")
+            .usize(blob.len())
+            .panic();
+    }
+    let mut checksum: u8 = 0;
+    let mut i = 0;
+    while i < blob.len() {
+        if i != index {
+            checksum = if sum {
+                checksum.wrapping_add(blob[i])
+            } else {
+                checksum ^ blob[i]
+            };
+        }
+        i += 1;
+    }
+    if checksum != blob[index] {
+        _Msg::new()
+            .str("checksum mismatch at index ")
+            .usize(index)
+            .str(": computed 0x")
+            .hex_lower(checksum)
+            .str(", found 0x")
+            .hex_lower(blob[index])
+            .panic();
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_quoted_printable!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&str`][str] expressions and literals into a static
+/// string slice encoded as quoted-printable.
+///
+/// This macro takes the same input as [`concat!`] and yields an expression of
+/// type [`&'static str`][str] which is the quoted-printable encoding of the
+/// concatenation as defined by [RFC 2045]. The following rules are used.
+///
+/// - The bytes `!` through `~`, except `=`, are written as is.
+/// - A space or tab is written as is unless it is the last byte of a line, in
+///   which case it is escaped.
+/// - Each `\r\n` is a hard line break and is written as is.
+/// - Every other byte, including `=`, a lone `\r` or `\n`, and each byte of a
+///   non-ASCII character, is escaped as `=` followed by two uppercase
+///   hexadecimal digits.
+/// - A soft line break `=\r\n` is inserted wherever needed to keep every line
+///   at most 76 characters long including the trailing `=`. An escape sequence
+///   is never split across lines.
+///
+/// [RFC 2045]: https://www.rfc-editor.org/rfc/rfc2045#section-6.7
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_quoted_printable;
+/// #
+/// const NAME: &str = "Zoë";
+/// const BODY: &str = concat_quoted_printable!("x=1 for ", NAME, " \r\n");
+/// assert_eq!(BODY, "x=3D1 for Zo=C3=AB=20\r\n");
+/// ```
+#[macro_export]
+macro_rules! concat_quoted_printable {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &str = $crate::concat!($($e),*);
+        const LEN: usize = $crate::_quoted_printable::<0>(SRC.as_bytes()).len();
+        const ARR: [u8; LEN] = $crate::_quoted_printable::<LEN>(SRC.as_bytes()).finish();
+        // SAFETY: The array only contains ASCII so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _quoted_printable<const N: usize>(src: &[u8]) -> _Buf<N> {
+    const MAX_LINE: usize = 76;
+    let mut buf = _Buf::new();
+    let mut col = 0;
+    let mut i = 0;
+    while i < src.len() {
+        let b = src[i];
+        if b == b'\r' && i + 1 < src.len() && src[i + 1] == b'\n' {
+            buf = buf.str("\r\n");
+            col = 0;
+            i += 2;
+            continue;
+        }
+        let line_end = i + 1 == src.len() || _starts_with_at(src, i + 1, b"\r\n");
+        let literal = match b {
+            b' ' | b'\t' => !line_end,
+            b'=' => false,
+            b'!'..=b'~' => true,
+            _ => false,
+        };
+        let width = if literal { 1 } else { 3 };
+        // Leave room for the `=` of a soft line break.
+        if col + width > MAX_LINE - 1 {
+            buf = buf.str("=\r\n");
+            col = 0;
+        }
+        buf = if literal {
+            buf.push(b)
+        } else {
+            buf.push(b'=').hex_upper(b)
+        };
+        col += width;
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_mirror!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice
+/// followed by its reverse.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static [T]`][slice] which is the concatenation
+/// followed by the concatenation in reverse order, so that the result reads
+/// the same forwards and backwards and is twice as long. The type may be
+/// followed by `drop_middle = <bool>; `, and if it is `true` the last element
+/// of the concatenation is not repeated, which yields a palindrome of odd
+/// length `2 * len - 1` instead.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_mirror;
+/// #
+/// const A: &[u8] = &[1, 2];
+/// const B: &[u8] = &[3];
+/// const EVEN: &[u8] = concat_mirror!([u8]: A, B);
+/// assert_eq!(EVEN, [1, 2, 3, 3, 2, 1]);
+/// const ODD: &[u8] = concat_mirror!([u8]: drop_middle = true; A, B);
+/// assert_eq!(ODD, [1, 2, 3, 2, 1]);
+/// ```
+#[macro_export]
+macro_rules! concat_mirror {
+    ([$T:ty]: drop_middle = $drop:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_mirror!([$T]: $drop; $($s),*)
+    };
+    ([$T:ty]: $($s:expr),* $(,)?) => {
+        $crate::_concat_mirror!([$T]: false; $($s),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_mirror {
+    ([$T:ty]: $drop:expr; $($s:expr),*) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const LEN: usize = if $drop && !SRC.is_empty() {
+            2 * SRC.len() - 1
+        } else {
+            2 * SRC.len()
+        };
+        const ARR: [$T; LEN] = $crate::_from_fn!([$T; LEN], |i| if i < SRC.len() {
+            SRC[i]
+        } else {
+            SRC[LEN - 1 - i]
+        });
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_popcount!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices and return the total number of set bits.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type `usize` which is the sum of `count_ones()` over all of
+/// the elements of the concatenation. The element type must be a primitive
+/// integer type. For signed types the bits of the two's complement
+/// representation are counted.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_popcount;
+/// #
+/// const READ: &[u8] = &[0b1111_0000];
+/// const WRITE: &[u8] = &[0b0000_0011, 0b1000_0000];
+/// const ENABLED: usize = concat_popcount!([u8]: READ, WRITE);
+/// assert_eq!(ENABLED, 7);
+/// ```
+#[macro_export]
+macro_rules! concat_popcount {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const COUNT: usize = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let mut count = 0;
+            let mut i = 0;
+            while i < arr.len() {
+                count += arr[i].count_ones() as usize;
+                i += 1;
+            }
+            count
+        };
+        COUNT
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_braille!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals into a static
+/// string slice of braille patterns.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static str`][str] in which each byte `b` of the
+/// concatenation is replaced by the braille pattern character `U+2800 + b`.
+/// The braille patterns map each bit of the byte to a dot, so this gives a
+/// compact visual representation of binary data. Each character is three
+/// bytes long in UTF-8, so the result is three times as long as the
+/// concatenation.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_braille;
+/// #
+/// const BITMAP: &[u8] = &[0x00, 0x01, 0xFF];
+/// const DOTS: &str = concat_braille!(BITMAP);
+/// assert_eq!(DOTS, "⠀⠁⣿");
+/// ```
+#[macro_export]
+macro_rules! concat_braille {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const LEN: usize = 3 * SRC.len();
+        const ARR: [u8; LEN] = $crate::_from_fn!([u8; LEN], |i| {
+            let b = SRC[i / 3];
+            match i % 3 {
+                0 => 0xE2,
+                1 => 0xA0 | (b >> 6),
+                _ => 0x80 | (b & 0x3F),
+            }
+        });
+        // SAFETY: Each group of three bytes is the UTF-8 encoding of a
+        // character in the range U+2800 to U+28FF so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_operands_sorted!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[T]`][slice] expressions into a static slice and
+/// assert that the operands are sorted by their first element.
+///
+/// This macro works exactly like [`concat_slices!`] except that it fails to
+/// compile unless the first element of each operand is greater than or equal
+/// to the first element of the operand before it. This keeps the entries of a
+/// long table in order as it is edited. Only the operands are checked, not the
+/// elements within them. Empty operands have no first element and are
+/// skipped. The panic message names the index of the first operand that is out
+/// of order.
+///
+/// The element type must support the `<` operator in `const`, see
+/// [element type requirements](crate#element-type-requirements).
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_operands_sorted;
+/// #
+/// const APPLE: &[u8] = &[b'a', 1];
+/// const BANANA: &[u8] = &[b'b', 2];
+/// const CHERRY: &[u8] = &[b'c', 3];
+/// const TABLE: &[u8] = concat_operands_sorted!([u8]: APPLE, BANANA, CHERRY);
+/// ```
+///
+/// The following fails to compile because `BANANA` was inserted after `CHERRY`.
+///
+/// ```compile_fail
+/// # use constcat::concat_operands_sorted;
+/// #
+/// const APPLE: &[u8] = &[b'a', 1];
+/// const BANANA: &[u8] = &[b'b', 2];
+/// const CHERRY: &[u8] = &[b'c', 3];
+/// const TABLE: &[u8] = concat_operands_sorted!([u8]: APPLE, CHERRY, BANANA);
+/// ```
+#[macro_export]
+macro_rules! concat_operands_sorted {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const ARR: &[$T] = {
+            let ops: &[&[$T]] = &[$($crate::concat_slices!([$T]: $s)),*];
+            let mut prev = ops.len();
+            let mut i = 0;
+            while i < ops.len() {
+                if !ops[i].is_empty() {
+                    if prev < ops.len() && ops[i][0] < ops[prev][0] {
+                        $crate::_Msg::new()
+                            .str("operand at index ")
+                            .usize(i)
+                            .str(" is out of order, its first element is less than that of operand ")
+                            .usize(prev)
+                            .panic();
+                    }
+                    prev = i;
+                }
+                i += 1;
+            }
+            $crate::concat_slices!([$T]: $($s),*)
+        };
+        ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_grid_str!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` [`&[bool]`][slice] expressions into a static string
+/// slice that draws them as a grid.
+///
+/// This macro requires the number of columns and the characters to draw to be
+/// specified in the form `cols = <usize>, on = <char>, off = <char>; ` before
+/// the comma separated expressions. The characters may be omitted, i.e.
+/// `cols = <usize>; `, in which case they default to `'#'` and `'.'`. It yields
+/// an expression of type [`&'static str`][str] with each element of the
+/// concatenation drawn as the `on` character if it is `true` and the `off`
+/// character otherwise, and with a newline between each row of `cols`
+/// elements. There is no trailing newline. The macro fails to compile if the
+/// total length is not a multiple of `cols`.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_grid_str;
+/// #
+/// const TOP: &[bool] = &[true, false, true];
+/// const BOTTOM: &[bool] = &[false, true, false];
+/// const PICTURE: &str = concat_grid_str!(cols = 3, on = '█', off = ' '; TOP, BOTTOM);
+/// assert_eq!(PICTURE, "█ █\n █ ");
+/// ```
+#[macro_export]
+macro_rules! concat_grid_str {
+    (cols = $cols:expr, on = $on:expr, off = $off:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_grid_str!($cols, $on, $off; $($s),*)
+    };
+    (cols = $cols:expr; $($s:expr),* $(,)?) => {
+        $crate::_concat_grid_str!($cols, '#', '.'; $($s),*)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _concat_grid_str {
+    ($cols:expr, $on:expr, $off:expr; $($s:expr),*) => {{
+        const SRC: &[bool] = $crate::concat_slices!([bool]: $($s),*);
+        const LEN: usize = $crate::_grid_str::<0>(SRC, $cols, $on, $off).len();
+        const ARR: [u8; LEN] = $crate::_grid_str::<LEN>(SRC, $cols, $on, $off).finish();
+        // SAFETY: The array only contains newlines and the UTF-8 encodings of
+        // the two characters so it is valid UTF-8.
+        unsafe { $crate::core::str::from_utf8_unchecked(&ARR) }
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _grid_str<const N: usize>(src: &[bool], cols: usize, on: char, off: char) -> _Buf<N> {
+    _chunk_count(src.len(), cols);
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < src.len() {
+        if i > 0 && i % cols == 0 {
+            buf = buf.push(b'\n');
+        }
+        buf = buf.char(if src[i] { on } else { off });
+        i += 1;
+    }
+    buf
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_contiguous!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` integer slices into a static slice and assert that the
+/// result is a contiguous sequence.
+///
+/// This macro works exactly like [`concat_slices!`] except that it fails to
+/// compile unless each element of the concatenation is exactly one greater
+/// than the element before it, for example `[5, 6, 7, 8]`. This catches
+/// missing or duplicate entries in tables indexed by enum discriminants or
+/// opcodes. The element type must be a primitive integer type. The panic
+/// message names the index of the first gap.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_contiguous;
+/// #
+/// const LOADS: &[u32] = &[0x10, 0x11];
+/// const STORES: &[u32] = &[0x12, 0x13];
+/// const OPCODES: &[u32] = concat_contiguous!([u32]: LOADS, STORES);
+/// ```
+///
+/// The following fails to compile because opcode `0x12` is missing.
+///
+/// ```compile_fail
+/// # use constcat::concat_contiguous;
+/// #
+/// const LOADS: &[u32] = &[0x10, 0x11];
+/// const OPCODES: &[u32] = concat_contiguous!([u32]: LOADS, &[0x13]);
+/// ```
+#[macro_export]
+macro_rules! concat_contiguous {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const ARR: &[$T] = {
+            let arr: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+            let mut i = 1;
+            while i < arr.len() {
+                if !(arr[i - 1] < arr[i] && arr[i] - 1 == arr[i - 1]) {
+                    $crate::_Msg::new()
+                        .str("concatenation is not contiguous at index ")
+                        .usize(i)
+                        .str(", found ")
+                        .int($crate::_int!(arr[i]))
+                        .str(" after ")
+                        .int($crate::_int!(arr[i - 1]))
+                        .panic();
+                }
+                i += 1;
+            }
+            arr
+        };
+        ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_prefix_compressed! and concat_prefix_decompress!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Encode sorted `const` [`&str`][str] expressions and literals into a static
+/// front coded byte slice.
+///
+/// This macro takes any number of comma-separated [`&str`][str] literals or
+/// constant expressions, which must be sorted in ascending byte order, and
+/// yields an expression of type [`&'static [u8]`][slice]. Each operand is
+/// stored as one entry, in order, consisting of
+///
+/// - one byte holding the length of the prefix it shares with the previous
+///   operand, which is zero for the first operand,
+/// - one byte holding the length of the remaining suffix,
+/// - the bytes of the suffix.
+///
+/// Sorted string tables often share long prefixes so this is usually much
+/// smaller than the concatenation. The macro fails to compile if the operands
+/// are not sorted or if a prefix or suffix is longer than 255 bytes. Use
+/// [`concat_prefix_decompress!`] to decode the table.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_prefix_compressed;
+/// #
+/// const TABLE: &[u8] = concat_prefix_compressed!("car", "cart", "cat");
+/// assert_eq!(TABLE, b"\x00\x03car\x03\x01t\x02\x01t");
+/// ```
+#[macro_export]
+macro_rules! concat_prefix_compressed {
+    ($($e:expr),* $(,)?) => {{
+        const STRS: &[&str] = &[$($e),*];
+        const LEN: usize = $crate::_prefix_compress::<0>(STRS).len();
+        const ARR: [u8; LEN] = $crate::_prefix_compress::<LEN>(STRS).finish();
+        &ARR
+    }};
+}
+
+/// Decode front coded `const` [`&[u8]`][slice] expressions and literals into
+/// a static slice of string slices.
+///
+/// This macro takes the same input as [`concat_bytes!`] and yields an
+/// expression of type [`&'static [&'static str]`][slice]. The concatenation is
+/// decoded as a sequence of entries in the format produced by
+/// [`concat_prefix_compressed!`]. The macro fails to compile if an entry is
+/// truncated, if a prefix is longer than the previous string, or if a decoded
+/// string is not valid UTF-8.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_prefix_compressed, concat_prefix_decompress};
+/// #
+/// const TABLE: &[u8] = concat_prefix_compressed!("alpha", "alphabet", "beta");
+/// const WORDS: &[&str] = concat_prefix_decompress!(TABLE);
+/// assert_eq!(WORDS, ["alpha", "alphabet", "beta"]);
+/// ```
+#[macro_export]
+macro_rules! concat_prefix_decompress {
+    ($($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const SHAPE: (usize, usize) = $crate::_prefix_shape(SRC);
+        const DATA: [u8; SHAPE.1] = $crate::_prefix_decompress(SRC);
+        const ENDS: [usize; SHAPE.0] = $crate::_prefix_ends(SRC);
+        const ARR: [&str; SHAPE.0] = $crate::_from_fn!([&'static str; SHAPE.0], |i| {
+            let start = if i == 0 { 0 } else { ENDS[i - 1] };
+            $crate::_prefix_str(&DATA, start, ENDS[i])
+        });
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _prefix_compress<const N: usize>(strs: &[&str]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < strs.len() {
+        let s = strs[i].as_bytes();
+        let mut shared = 0;
+        if i > 0 {
+            let prev = strs[i - 1].as_bytes();
+            while shared < prev.len() && shared < s.len() && prev[shared] == s[shared] {
+                shared += 1;
+            }
+            if shared < prev.len() && (shared == s.len() || s[shared] < prev[shared]) {
+                _Msg::new()
+                    .str("operand at index ")
+                    .usize(i)
+                    .str(" is not sorted after the previous operand")
+                    .panic();
+            }
+        }
+        let suffix = s.len() - shared;
+        if shared > 255 || suffix > 255 {
+            _Msg::new()
+                .str("operand at index ")
+                .usize(i)
+                .str(" has a prefix or suffix longer than 255 bytes")
+                .panic();
+        }
+        buf = buf
+            .push(shared as u8)
+            .push(suffix as u8)
+            .bytes(_subslice(s, shared, s.len()));
+        i += 1;
+    }
+    buf
+}
+
+/// Validates the front coded table and returns the number of strings and the
+/// total length of the decoded strings.
+#[doc(hidden)]
+pub const fn _prefix_shape(src: &[u8]) -> (usize, usize) {
+    let mut count = 0;
+    let mut total = 0;
+    let mut prev = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if i + 2 > src.len() || i + 2 + src[i + 1] as usize > src.len() {
+            _Msg::new()
+                .str("front coded entry at byte index ")
+                .usize(i)
+                .str(" is truncated")
+                .panic();
+        }
+        let (shared, suffix) = (src[i] as usize, src[i + 1] as usize);
+        if shared > prev {
+            _Msg::new()
+                .str("front coded entry at byte index ")
+                .usize(i)
+                .str(" shares ")
+                .usize(shared)
+                .str(" bytes with a previous string of length ")
+                .usize(prev)
+                .panic();
+        }
+        prev = shared + suffix;
+        total += prev;
+        count += 1;
+        i += 2 + suffix;
+    }
+    (count, total)
+}
+
+#[doc(hidden)]
+pub const fn _prefix_decompress<const N: usize>(src: &[u8]) -> [u8; N] {
+    let mut out = [0; N];
+    let mut len = 0;
+    let mut prev_start = 0;
+    let mut i = 0;
+    while i < src.len() {
+        let (shared, suffix) = (src[i] as usize, src[i + 1] as usize);
+        let start = len;
+        let mut k = 0;
+        while k < shared {
+            out[len] = out[prev_start + k];
+            len += 1;
+            k += 1;
+        }
+        k = 0;
+        while k < suffix {
+            out[len] = src[i + 2 + k];
+            len += 1;
+            k += 1;
+        }
+        prev_start = start;
+        i += 2 + suffix;
+    }
+    out
+}
+
+#[doc(hidden)]
+pub const fn _prefix_ends<const N: usize>(src: &[u8]) -> [usize; N] {
+    let mut ends = [0; N];
+    let mut end = 0;
+    let mut j = 0;
+    let mut i = 0;
+    while i < src.len() {
+        let (shared, suffix) = (src[i] as usize, src[i + 1] as usize);
+        end += shared + suffix;
+        ends[j] = end;
+        j += 1;
+        i += 2 + suffix;
+    }
+    ends
+}
+
+#[doc(hidden)]
+pub const fn _prefix_str(data: &'static [u8], start: usize, end: usize) -> &'static str {
+    match core::str::from_utf8(_subslice(data, start, end)) {
+        Ok(s) => s,
+        Err(_) => _Msg::new()
+            .str("front coded string at byte index ")
+            .usize(start)
+            .str(" of the decoded data is not valid UTF-8")
+            .panic(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_zigzag! and concat_zigzag_decode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` signed integer slices and encode the result as
+/// zig-zag varints.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static [u8]`][slice] with each element of the
+/// concatenation encoded in the same way as the protobuf `sint32` and `sint64`
+/// types. Each value `n` is first zig-zag encoded, mapping `0, -1, 1, -2, 2,
+/// ...` to `0, 1, 2, 3, 4, ...`, i.e. `(n << 1) ^ (n >> (BITS - 1))`, so that
+/// values of small magnitude have small encodings. The result is then written
+/// as an unsigned LEB128 varint, seven bits at a time starting with the least
+/// significant bits, with the high bit of each byte set if more bytes follow.
+/// The element type must be a primitive signed integer type.
+///
+/// Use [`concat_zigzag_decode!`] to decode the result.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_zigzag;
+/// #
+/// const DELTAS: &[i32] = &[0, -1, 1];
+/// const BIG: &[i32] = &[-64, 64];
+/// const PACKED: &[u8] = concat_zigzag!([i32]: DELTAS, BIG);
+/// assert_eq!(PACKED, [0x00, 0x01, 0x02, 0x7F, 0x80, 0x01]);
+/// ```
+#[macro_export]
+macro_rules! concat_zigzag {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const INTS: [u128; SRC.len()] = $crate::_from_fn!([u128; SRC.len()], |i| {
+            let n = SRC[i] as i128;
+            ((n << 1) ^ (n >> 127)) as u128
+        });
+        const LEN: usize = $crate::_varints::<0>(&INTS).len();
+        const ARR: [u8; LEN] = $crate::_varints::<LEN>(&INTS).finish();
+        &ARR
+    }};
+}
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and decode
+/// the result as zig-zag varints.
+///
+/// This macro requires the type of integer to be specified in the form `[T]: `
+/// before the expressions accepted by [`concat_bytes!`]. It yields an
+/// expression of type [`&'static [T]`][slice]. The concatenation is decoded as
+/// a sequence of varints in the format produced by [`concat_zigzag!`]. The
+/// macro fails to compile if the last varint is truncated or if a value does
+/// not fit in `T`. The panic message names the index of the value.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_zigzag, concat_zigzag_decode};
+/// #
+/// const PACKED: &[u8] = &[0x03, 0x80, 0x01];
+/// const DELTAS: &[i16] = concat_zigzag_decode!([i16]: PACKED);
+/// assert_eq!(DELTAS, [-2, 64]);
+///
+/// const DATA: &[i64] = &[i64::MIN, -1, 0, i64::MAX];
+/// assert_eq!(concat_zigzag_decode!([i64]: concat_zigzag!([i64]: DATA)), DATA);
+/// ```
+#[macro_export]
+macro_rules! concat_zigzag_decode {
+    ([$T:ty]: $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const INTS: [u128; $crate::_varint_count(SRC)] = $crate::_varint_decode(SRC);
+        const ARR: [$T; INTS.len()] = $crate::_from_fn!([$T; INTS.len()], |i| {
+            let n = ((INTS[i] >> 1) as i128) ^ -((INTS[i] & 1) as i128);
+            if n as $T as i128 != n {
+                $crate::_Msg::new()
+                    .str("value at index ")
+                    .usize(i)
+                    .str(" is out of range: ")
+                    .int($crate::_int!(n))
+                    .panic();
+            }
+            n as $T
+        });
+        &ARR
+    }};
+}
+
+#[doc(hidden)]
+pub const fn _varints<const N: usize>(ints: &[u128]) -> _Buf<N> {
+    let mut buf = _Buf::new();
+    let mut i = 0;
+    while i < ints.len() {
+        buf = buf.leb128(ints[i]);
+        i += 1;
+    }
+    buf
+}
+
+/// Validates the varints and returns how many there are.
+#[doc(hidden)]
+pub const fn _varint_count(src: &[u8]) -> usize {
+    let mut count = 0;
+    let mut bits = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if bits == 126 && src[i] > 0b11 {
+            _Msg::new()
+                .str("varint at index ")
+                .usize(count)
+                .str(" overflows `u128`")
+                .panic();
+        }
+        if src[i] & 0x80 == 0 {
+            count += 1;
+            bits = 0;
+        } else {
+            bits += 7;
+        }
+        i += 1;
+    }
+    if bits > 0 {
+        _Msg::new()
+            .str("varint at index ")
+            .usize(count)
+            .str(" is truncated")
+            .panic();
+    }
+    count
+}
+
+#[doc(hidden)]
+pub const fn _varint_decode<const N: usize>(src: &[u8]) -> [u128; N] {
+    let mut ints = [0; N];
+    let mut shift = 0;
+    let mut j = 0;
+    let mut i = 0;
+    while i < src.len() {
+        ints[j] |= ((src[i] & 0x7F) as u128) << shift;
+        if src[i] & 0x80 == 0 {
+            j += 1;
+            shift = 0;
+        } else {
+            shift += 7;
+        }
+        i += 1;
+    }
+    ints
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// concat_varint! and concat_varint_decode!
+////////////////////////////////////////////////////////////////////////////////
+
+/// Concatenate `const` unsigned integer slices and encode the result as
+/// varints.
+///
+/// This macro takes the same input as [`concat_slices!`] and yields an
+/// expression of type [`&'static [u8]`][slice] with each element of the
+/// concatenation written as an unsigned LEB128 varint, the encoding used by
+/// the protobuf `uint32` and `uint64` types. Each value is written seven bits
+/// at a time starting with the least significant bits, with the high bit of
+/// each byte set if more bytes follow, and using as few bytes as possible.
+/// Values below 128 take one byte. The element type must be a primitive
+/// unsigned integer type.
+///
+/// See [`concat_zigzag!`] for signed integers. Use [`concat_varint_decode!`]
+/// to decode the result.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::concat_varint;
+/// #
+/// const SMALL: &[u64] = &[0, 1, 127];
+/// const LARGE: &[u64] = &[300];
+/// const PACKED: &[u8] = concat_varint!([u64]: SMALL, LARGE);
+/// assert_eq!(PACKED, [0x00, 0x01, 0x7F, 0xAC, 0x02]);
+/// ```
+#[macro_export]
+macro_rules! concat_varint {
+    ([$T:ty]: $($s:expr),* $(,)?) => {{
+        const SRC: &[$T] = $crate::concat_slices!([$T]: $($s),*);
+        const INTS: [u128; SRC.len()] = $crate::_from_fn!([u128; SRC.len()], |i| SRC[i] as u128);
+        const LEN: usize = $crate::_varints::<0>(&INTS).len();
+        const ARR: [u8; LEN] = $crate::_varints::<LEN>(&INTS).finish();
+        &ARR
+    }};
+}
+
+/// Concatenate `const` [`&[u8]`][slice] expressions and literals and decode
+/// the result as varints.
+///
+/// This macro requires the type of integer to be specified in the form `[T]: `
+/// before the expressions accepted by [`concat_bytes!`]. It yields an
+/// expression of type [`&'static [T]`][slice]. The concatenation is decoded as
+/// a sequence of unsigned LEB128 varints in the format produced by
+/// [`concat_varint!`], though encodings that are longer than necessary are
+/// also accepted. The macro fails to compile if the last varint is truncated
+/// or if a value does not fit in `T`. The panic message names the index of the
+/// value.
+///
+/// # Examples
+///
+/// ```
+/// # use constcat::{concat_varint, concat_varint_decode};
+/// #
+/// const PACKED: &[u8] = &[0xAC, 0x02, 0x05];
+/// const VALUES: &[u32] = concat_varint_decode!([u32]: PACKED);
+/// assert_eq!(VALUES, [300, 5]);
+///
+/// const DATA: &[u64] = &[0, 128, u64::MAX];
+/// assert_eq!(concat_varint_decode!([u64]: concat_varint!([u64]: DATA)), DATA);
+/// ```
+#[macro_export]
+macro_rules! concat_varint_decode {
+    ([$T:ty]: $($e:expr),* $(,)?) => {{
+        const SRC: &[u8] = $crate::concat_bytes!($($e),*);
+        const INTS: [u128; $crate::_varint_count(SRC)] = $crate::_varint_decode(SRC);
+        const ARR: [$T; INTS.len()] = $crate::_from_fn!([$T; INTS.len()], |i| {
+            if INTS[i] as $T as u128 != INTS[i] {
+                $crate::_Msg::new()
+                    .str("value at index ")
+                    .usize(i)
+                    .str(" is out of range: ")
+                    .u128(INTS[i])
+                    .panic();
+            }
+            INTS[i] as $T
+        });
+        &ARR
+    }};
+}
+
+////////////////////////////////////////////////////////////////////////////////
+// Private helpers
+////////////////////////////////////////////////////////////////////////////////
+
+/// A fixed capacity byte buffer that can be written to in `const` contexts.
+///
+/// Every write is counted even if it does not fit, so the same routine can be
+/// run once with a zero capacity buffer to measure the output length and then
+/// again with a buffer of exactly that length to fill it.
+#[doc(hidden)]
+pub struct _Buf<const N: usize> {
+    buf: [u8; N],
+    len: usize,
+}
+
+/// A buffer used to format `const` panic messages.
+///
+/// Formatting machinery is not available in `const` contexts so this provides
+/// just enough to include indices and values in a message. Anything past the
+/// capacity of the buffer is silently truncated.
+#[doc(hidden)]
+pub type _Msg = _Buf<256>;
+
+#[allow(clippy::len_without_is_empty, clippy::new_without_default)]
+impl<const N: usize> _Buf<N> {
+    pub const fn new() -> Self {
+        Self {
+            buf: [0; N],
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn push(mut self, b: u8) -> Self {
+        if self.len < N {
+            self.buf[self.len] = b;
+        }
+        self.len += 1;
+        self
+    }
+
+    pub const fn bytes(mut self, bytes: &[u8]) -> Self {
+        let mut i = 0;
+        while i < bytes.len() {
+            self = self.push(bytes[i]);
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn str(self, s: &str) -> Self {
+        self.bytes(s.as_bytes())
+    }
+
+    pub const fn char(self, c: char) -> Self {
+        let c = c as u32;
+        if c < 0x80 {
+            self.push(c as u8)
+        } else if c < 0x800 {
+            self.push(0xC0 | (c >> 6) as u8)
+                .push(0x80 | (c & 0x3F) as u8)
+        } else if c < 0x10000 {
+            self.push(0xE0 | (c >> 12) as u8)
+                .push(0x80 | ((c >> 6) & 0x3F) as u8)
+                .push(0x80 | (c & 0x3F) as u8)
+        } else {
+            self.push(0xF0 | (c >> 18) as u8)
+                .push(0x80 | ((c >> 12) & 0x3F) as u8)
+                .push(0x80 | ((c >> 6) & 0x3F) as u8)
+                .push(0x80 | (c & 0x3F) as u8)
+        }
+    }
+
+    /// Writes a byte as two lowercase hexadecimal digits.
+    pub const fn hex_lower(self, b: u8) -> Self {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+        self.push(DIGITS[(b >> 4) as usize])
+            .push(DIGITS[(b & 0xF) as usize])
+    }
+
+    /// Writes a byte as two uppercase hexadecimal digits.
+    pub const fn hex_upper(self, b: u8) -> Self {
+        const DIGITS: &[u8; 16] = b"0123456789ABCDEF";
+        self.push(DIGITS[(b >> 4) as usize])
+            .push(DIGITS[(b & 0xF) as usize])
+    }
+
+    pub const fn u128(mut self, mut n: u128) -> Self {
+        let mut digits = [0; 39];
+        let mut i = digits.len();
+        loop {
+            i -= 1;
+            digits[i] = b'0' + (n % 10) as u8;
+            n /= 10;
+            if n == 0 {
+                break;
+            }
+        }
+        while i < digits.len() {
+            self = self.push(digits[i]);
+            i += 1;
+        }
+        self
+    }
+
+    pub const fn i128(self, n: i128) -> Self {
+        self.int((n < 0, n.unsigned_abs()))
+    }
+
+    pub const fn usize(self, n: usize) -> Self {
+        self.u128(n as u128)
+    }
+
+    /// Writes an integer in the sign and magnitude form produced by `_int!`.
+    pub const fn int(self, (neg, abs): (bool, u128)) -> Self {
+        if neg {
+            self.push(b'-').u128(abs)
+        } else {
+            self.u128(abs)
+        }
+    }
+
+    /// Writes an integer as an unsigned LEB128 varint.
+    pub const fn leb128(mut self, mut n: u128) -> Self {
+        while n >= 0x80 {
+            self = self.push(n as u8 | 0x80);
+            n >>= 7;
+        }
+        self.push(n as u8)
+    }
+
+    /// Returns the bytes written so far that fit in the buffer.
+    pub const fn as_bytes(&self) -> &[u8] {
+        let len = if self.len < N { self.len } else { N };
+        self.buf.split_at(len).0
+    }
+
+    /// Returns the buffer, asserting that it was filled exactly.
+    pub const fn finish(self) -> [u8; N] {
+        if self.len != N {
+            panic!("invalid length");
+        }
+        self.buf
+    }
+
+    pub const fn panic(self) -> ! {
+        let bytes = self.as_bytes();
+        // The buffer may have been truncated in the middle of a character so
+        // only use the valid prefix.
+        let msg = match core::str::from_utf8(bytes) {
+            Ok(msg) => msg,
+            Err(err) => {
+                let (valid, _) = bytes.split_at(err.valid_up_to());
+                // SAFETY: The bytes up to `valid_up_to` are valid UTF-8.
+                unsafe { core::str::from_utf8_unchecked(valid) }
+            }
+        };
+        panic!("{}", msg)
+    }
+}
+
+/// Converts an integer expression of any width or signedness into a sign and
+/// magnitude pair without losing precision.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _int {
+    ($e:expr) => {{
+        let int = $e;
+        #[allow(unused_comparisons)]
+        let neg = int < 0;
+        if neg {
+            (true, (int as i128).unsigned_abs())
+        } else {
+            (false, int as u128)
+        }
+    }};
+}
+
+/// Returns the bytes in the range `start..end`.
+const fn _subslice(bytes: &[u8], start: usize, end: usize) -> &[u8] {
+    bytes.split_at(end).0.split_at(start).1
+}
+
+const fn _bytes_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && _starts_with_at(a, 0, b)
+}
+
+/// Returns whether `needle` occurs in `bytes` starting at `pos`.
+const fn _starts_with_at(bytes: &[u8], pos: usize, needle: &[u8]) -> bool {
+    if pos + needle.len() > bytes.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < needle.len() {
+        if bytes[pos + i] != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Returns the index of the first occurrence of `needle` in `haystack`.
+const fn _find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    let mut i = 0;
+    while i + needle.len() <= haystack.len() {
+        if _starts_with_at(haystack, i, needle) {
+            return Some(i);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Builds an array of type `[$T; $len]` where the element at each index `$i`
+/// is given by the expression `$e`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _from_fn {
+    ([$T:ty; $len:expr], |$i:ident| $e:expr) => {{
+        use $crate::core::mem::MaybeUninit;
+        let mut arr: [MaybeUninit<$T>; $len] = [MaybeUninit::uninit(); $len];
+        let mut $i = 0;
+        while $i < $len {
+            arr[$i] = MaybeUninit::new($e);
+            $i += 1;
+        }
+        // SAFETY: Every element of the array was initialized in the loop
+        // above and `MaybeUninit<T>` has the same layout as `T`. See the
+        // comment in `_concat_slices!` for more information.
+        unsafe { $crate::core::mem::transmute::<[MaybeUninit<$T>; $len], [$T; $len]>(arr) }
+    }};
+}
@@ -27,6 +27,12 @@ fn concat_smoke() {
 
     const TEST7: &str = concat!("before ", env!("CARGO_PKG_NAME"), " after");
     assert_eq!(TEST7, "before constcat after");
+
+    const TEST8: &str = concat!(upper: "mixed Case ", lower: TEST7, upper: 'ß', lower: true, 1);
+    assert_eq!(TEST8, "MIXED CASE before constcat afterßtrue1");
+
+    const TEST9: &str = concat!(1, 2, 3, 4, 5, 6, 7, 8, 9, upper: "a", "b", lower: "C",);
+    assert_eq!(TEST9, "123456789Abc");
 }
 
 #[test]
@@ -117,6 +123,18 @@ fn concat_slices_smoke() {
             I("Try")
         ]
     );
+
+    const COUNT: usize = 2;
+    const TEST15: &[i32] = concat_slices!([i32]: &[1], TEST2 * COUNT, &[4, 5] * 0, TEST0 * 5, &[6]);
+    assert_eq!(TEST15, [1, 1, 2, 3, 1, 2, 3, 6]);
+
+    const TEST16: &[i32] = concat_slices!([i32]: [7, 8] * (COUNT + 1),);
+    assert_eq!(TEST16, [7, 8, 7, 8, 7, 8]);
+
+    const INNER: &[i32; 2] = &[1, 2];
+    #[allow(clippy::borrow_deref_ref)]
+    const TEST17: &[i32] = concat_slices!([i32]: &*INNER, &[3], *INNER, (&*INNER) * 2);
+    assert_eq!(TEST17, [1, 2, 3, 1, 2, 1, 2, 1, 2]);
 }
 
 #[test]
@@ -133,3 +151,1945 @@ fn concat_namespacing() {
     const TEST0: &str = concat!("test", 10, 'b', true);
     assert_eq!(TEST0, "test10btrue");
 }
+
+#[test]
+fn concat_wide_smoke() {
+    use constcat::concat_wide;
+
+    const TEST0: &[u16] = concat_wide!([u16]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u16] = concat_wide!([u16]: &[1, 2], &[0xFFFF]);
+    assert_eq!(TEST1, [1, 2, 0xFFFF]);
+    assert_eq!(TEST1.as_ptr() as usize % core::mem::align_of::<u16>(), 0);
+
+    const TEST2: &[u32] = concat_wide!([u32]: &[1], &[u32::MAX], &[3],);
+    assert_eq!(TEST2, [1, u32::MAX, 3]);
+    assert_eq!(TEST2.as_ptr() as usize % core::mem::align_of::<u32>(), 0);
+
+    const TEST3: &[u64] = concat_wide!([u64]: &[u64::MAX], &[0]);
+    assert_eq!(TEST3, [u64::MAX, 0]);
+    assert_eq!(TEST3.as_ptr() as usize % core::mem::align_of::<u64>(), 0);
+}
+
+#[test]
+fn concat_utf16_smoke() {
+    use constcat::concat_utf16;
+
+    const TEST0: &[u16] = concat_utf16!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u16] = concat_utf16!("test", 10, 'b', true);
+    assert_eq!(TEST1, "test10btrue".encode_utf16().collect::<Vec<_>>());
+
+    const TEST2: &str = "héllo wörld ✨ 🎉 𝄞";
+    const TEST3: &[u16] = concat_utf16!(TEST2, "!");
+    assert_eq!(
+        TEST3,
+        "héllo wörld ✨ 🎉 𝄞!".encode_utf16().collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn concat_ascending_smoke() {
+    use constcat::concat_ascending;
+
+    const TEST0: &[i32] = concat_ascending!([i32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = concat_ascending!([i32]: &[-1, 2], &[3], &[7, 100],);
+    assert_eq!(TEST1, [-1, 2, 3, 7, 100]);
+
+    const TEST2: &[char] = concat_ascending!([char]: &['a', 'b'], &['z']);
+    assert_eq!(TEST2, ['a', 'b', 'z']);
+}
+
+#[test]
+fn concat_int_csv_smoke() {
+    use constcat::concat_int_csv;
+
+    const TEST0: &str = concat_int_csv!(sep = ",";);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_int_csv!(sep = ","; 1, 22, 333);
+    assert_eq!(TEST1, "1,22,333");
+
+    const TEST2: &str = concat_int_csv!(sep = ", "; 0, -1, i8::MIN, u8::MAX,);
+    assert_eq!(TEST2, "0, -1, -128, 255");
+
+    const SEP: &str = " | ";
+    const TEST3: &str = concat_int_csv!(sep = SEP; i128::MIN, u128::MAX, 7usize);
+    assert_eq!(
+        TEST3,
+        "-170141183460469231731687303715884105728 | 340282366920938463463374607431768211455 | 7"
+    );
+}
+
+#[test]
+fn concat_join_char_smoke() {
+    use constcat::concat_join_char;
+
+    const TEST0: &str = concat_join_char!('/';);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_join_char!('/'; "a", "b", "c");
+    assert_eq!(TEST1, "a/b/c");
+
+    const TEST2: &str = concat_join_char!('/'; "/a//", "//b/", "c/",);
+    assert_eq!(TEST2, "/a/b/c/");
+
+    const TEST3: &str = concat_join_char!('/'; "a/", "/", "", "/b");
+    assert_eq!(TEST3, "a/b");
+
+    const TEST4: &str = concat_join_char!('/'; "/", "usr", "a//b");
+    assert_eq!(TEST4, "/usr/a//b");
+
+    const TEST5: &str = concat_join_char!('→'; "α→", "β", "→→γ");
+    assert_eq!(TEST5, "α→β→γ");
+
+    const TEST6: &str = concat_join_char!(':'; "one", 2, true);
+    assert_eq!(TEST6, "one:2:true");
+}
+
+#[test]
+fn static_map_smoke() {
+    use constcat::{static_map, StaticMap};
+
+    const TEST0: StaticMap = static_map! {};
+    assert!(TEST0.is_empty());
+    assert_eq!(TEST0.get(""), None);
+
+    const KEY: &str = "two";
+    const TEST1: StaticMap = static_map! {
+        "one" => "1",
+        KEY => concat!("t", "wo"),
+        "" => "empty",
+        "three" => "",
+        "🎉" => 3,
+    };
+    assert_eq!(TEST1.len(), 5);
+    assert_eq!(TEST1.get("one"), Some("1"));
+    assert_eq!(TEST1.get("two"), Some("two"));
+    assert_eq!(TEST1.get(""), Some("empty"));
+    assert_eq!(TEST1.get("three"), Some(""));
+    assert_eq!(TEST1.get("🎉"), Some("3"));
+    assert_eq!(TEST1.get("on"), None);
+    assert!(TEST1.contains_key("three"));
+    assert!(!TEST1.contains_key("four"));
+
+    const TEST2: Option<&str> = TEST1.get("one");
+    assert_eq!(TEST2, Some("1"));
+}
+
+#[test]
+fn key_bytes_smoke() {
+    use constcat::key_bytes;
+
+    const TEST0: &[u8; 0] = key_bytes!(0:);
+    assert_eq!(TEST0, &[]);
+
+    const LEN: usize = 8;
+    const TEST1: &[u8; LEN] = key_bytes!(LEN: &[1, 2, 3], &[4; 5],);
+    assert_eq!(TEST1, &[1, 2, 3, 4, 4, 4, 4, 4]);
+
+    const TEST2: &[u8; 32] = key_bytes!(32: TEST1, &[0; 24]);
+    assert_eq!(&TEST2[..8], TEST1);
+    assert_eq!(&TEST2[8..], &[0; 24]);
+}
+
+#[test]
+fn concat_slices_arrays() {
+    use constcat::concat_slices;
+
+    const fn header() -> [u8; 4] {
+        *b"HEAD"
+    }
+    const TAIL: &[u8] = b"TAIL";
+    const ARRAY: [u8; 2] = [1, 2];
+    const ARRAY_REF: &[u8; 2] = &[3, 4];
+
+    const TEST0: &[u8] = concat_slices!([u8]: header(), TAIL);
+    assert_eq!(TEST0, b"HEADTAIL");
+
+    const TEST1: &[u8] = concat_slices!([u8]: ARRAY, ARRAY_REF, [5, 6], &[7]);
+    assert_eq!(TEST1, [1, 2, 3, 4, 5, 6, 7]);
+
+    const TEST2: &[u16] = concat_slices!([u16]: [1; 3], [2; 0], [3; 1]);
+    assert_eq!(TEST2, [1, 1, 1, 3]);
+
+    const TEST3: &[u8] = concat_slices!([u8]: (header()) * 2, *ARRAY_REF);
+    assert_eq!(TEST3, b"HEADHEAD\x03\x04");
+}
+
+#[test]
+fn concat_slices_many_operands() {
+    use constcat::concat_slices;
+
+    mod m {
+        pub const A: &[u8] = &[1];
+    }
+    const TEST0: &[u8] = concat_slices!([u8]:
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+        m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A, m::A,
+    );
+    assert_eq!(TEST0.len(), 130);
+}
+
+#[test]
+fn concat_bytes_map_smoke() {
+    use constcat::concat_bytes_map;
+
+    const fn identity(b: u8) -> u8 {
+        b
+    }
+    const fn rot13(b: u8) -> u8 {
+        match b {
+            b'a'..=b'z' => (b - b'a' + 13) % 26 + b'a',
+            b'A'..=b'Z' => (b - b'A' + 13) % 26 + b'A',
+            _ => b,
+        }
+    }
+
+    const TEST0: &[u8] = concat_bytes_map!(identity;);
+    assert_eq!(TEST0, b"");
+
+    const HELLO: &[u8] = b"Hello";
+    const TEST1: &[u8] = concat_bytes_map!(rot13; HELLO, &[0x20], b"World!".as_slice(),);
+    assert_eq!(TEST1, b"Uryyb Jbeyq!");
+
+    const TEST2: &[u8] = concat_bytes_map!(rot13; TEST1);
+    assert_eq!(TEST2, b"Hello World!");
+}
+
+#[test]
+fn concat_bytes_xor_smoke() {
+    use constcat::concat_bytes_xor;
+
+    const TEST0: &[u8] = concat_bytes_xor!(mask = 0xFF;);
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = concat_bytes_xor!(mask = 0xFF; &[0x00, 0x0F], &[0xF0],);
+    assert_eq!(TEST1, [0xFF, 0xF0, 0x0F]);
+
+    const KEY: &[u8] = &[0x01, 0x02, 0x03];
+    const TEST2: &[u8] = concat_bytes_xor!(key = KEY; &[0; 4], &[0xFF]);
+    assert_eq!(TEST2, [0x01, 0x02, 0x03, 0x01, 0xFD]);
+
+    const TEST3: &[u8] = concat_bytes_xor!(key = KEY; TEST2);
+    assert_eq!(TEST3, [0, 0, 0, 0, 0xFF]);
+}
+
+#[test]
+fn concat_bytes_at_smoke() {
+    use constcat::concat_bytes_at;
+
+    const TEST0: &[u8] = concat_bytes_at!();
+    assert_eq!(TEST0, b"");
+
+    const A: &[u8] = &[1, 2];
+    const B: &[u8] = &[3];
+    const C: &[u8] = &[4, 5, 6];
+    const TEST1: &[u8] = concat_bytes_at!((A, 0), (B, 4), (C, 8),);
+    assert_eq!(TEST1, [1, 2, 0, 0, 3, 0, 0, 0, 4, 5, 6]);
+
+    const TEST2: &[u8] = concat_bytes_at!((C, 3), (A, 1), (TEST0, 2));
+    assert_eq!(TEST2, [0, 1, 2, 4, 5, 6]);
+
+    const TEST3: &[u8] = concat_bytes_at!((A, 0), (B, 2), (C, 3));
+    assert_eq!(TEST3, [1, 2, 3, 4, 5, 6]);
+
+    const TEST4: &[u8] = concat_bytes_at!((A, 0), (TEST0, 4));
+    assert_eq!(TEST4, [1, 2, 0, 0]);
+}
+
+#[test]
+fn concat_cast_smoke() {
+    use constcat::concat_cast;
+
+    const TEST0: &[u16] = concat_cast!([u8] -> u16:);
+    assert_eq!(TEST0, []);
+
+    const A: &[u8] = &[0, 1, 0xFF];
+    const TEST1: &[u16] = concat_cast!([u8] -> u16: A, &[0x80],);
+    assert_eq!(TEST1, [0, 1, 0xFF, 0x80]);
+
+    const B: &[i8] = &[-128, -1, 0, 127];
+    const TEST2: &[i64] = concat_cast!([i8] -> i64: B);
+    assert_eq!(TEST2, [-128, -1, 0, 127]);
+
+    const TEST3: &[u8] = concat_cast!([i8] -> u8: B);
+    assert_eq!(TEST3, [0x80, 0xFF, 0, 0x7F]);
+
+    const TEST4: &[f64] = concat_cast!([u8] -> f64: A);
+    assert_eq!(TEST4, [0., 1., 255.]);
+}
+
+#[test]
+fn concat_ascii_smoke() {
+    use constcat::concat_ascii;
+
+    const TEST0: &str = concat_ascii!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_ascii!("test", 10, 'b', true,);
+    assert_eq!(TEST1, "test10btrue");
+
+    const TEST2: &str = concat_ascii!("\0", TEST1, "\x7F");
+    assert_eq!(TEST2, "\0test10btrue\x7F");
+}
+
+#[test]
+fn concat_mark_every_smoke() {
+    use constcat::concat_mark_every;
+
+    const TEST0: &[u8] = concat_mark_every!([u8]: n = 2, mark = 0xAA;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_mark_every!([u8]: n = 2, mark = 0xAA; &[1, 2], &[3, 4],);
+    assert_eq!(TEST1, [1, 2, 0xAA, 3, 4, 0xAA]);
+
+    const TEST2: &[u8] = concat_mark_every!([u8]: n = 1, mark = 0; &[1, 2, 3]);
+    assert_eq!(TEST2, [1, 0, 2, 0, 3, 0]);
+
+    const TEST3: &[char] = concat_mark_every!([char]: n = 3, mark = '-'; &['a', 'b'], &['c', 'd']);
+    assert_eq!(TEST3, ['a', 'b', 'c', '-', 'd']);
+}
+
+#[test]
+fn concat_require_contains_smoke() {
+    use constcat::{concat_bytes_require_contains, concat_require_contains};
+
+    const TEST0: &str = concat_require_contains!("";);
+    assert_eq!(TEST0, "");
+
+    const NEEDLE: &str = "SPDX";
+    const TEST1: &str = concat_require_contains!(NEEDLE; "// SP", "DX: MIT",);
+    assert_eq!(TEST1, "// SPDX: MIT");
+
+    const TEST2: &str = concat_require_contains!("10"; "test", 10, true);
+    assert_eq!(TEST2, "test10true");
+
+    const TEST3: &[u8] = concat_bytes_require_contains!(&[2, 3]; &[1, 2], &[3, 4]);
+    assert_eq!(TEST3, [1, 2, 3, 4]);
+}
+
+#[test]
+fn concat_forbid_contains_smoke() {
+    use constcat::{concat_bytes_forbid_contains, concat_forbid_contains};
+
+    const TEST0: &str = concat_forbid_contains!("x";);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_forbid_contains!(";"; "SELECT ", "*", " FROM t",);
+    assert_eq!(TEST1, "SELECT * FROM t");
+
+    const TEST2: &[u8] = concat_bytes_forbid_contains!(&[2, 1]; &[1, 2], &[3, 4]);
+    assert_eq!(TEST2, [1, 2, 3, 4]);
+}
+
+#[test]
+fn concat_with_offsets_smoke() {
+    use constcat::concat_with_offsets;
+
+    const TEST0: (&[i32], &[usize]) = concat_with_offsets!([i32]:);
+    assert_eq!(TEST0, (&[][..], &[][..]));
+
+    const A: &[i32] = &[1, 2, 3];
+    const B: &[i32] = &[];
+    const TEST1: (&[i32], &[usize]) = concat_with_offsets!([i32]: A, B, &[4], [5, 6],);
+    assert_eq!(TEST1.0, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(TEST1.1, [0, 3, 3, 4]);
+
+    const TEST2: (&[&str], &[usize]) = concat_with_offsets!([&str]: &["a"], &["b", "c"]);
+    assert_eq!(TEST2.0, ["a", "b", "c"]);
+    assert_eq!(TEST2.1, [0, 1]);
+}
+
+#[test]
+fn concat_lf_smoke() {
+    use constcat::concat_lf;
+
+    const TEST0: &str = concat_lf!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_lf!("a\r\nb\rc\nd", "\r\r\n\n",);
+    assert_eq!(TEST1, "a\nb\nc\nd\n\n\n");
+
+    const TEST2: &str = concat_lf!("one\r", "\ntwo", 3);
+    assert_eq!(TEST2, "one\ntwo3");
+
+    const TEST3: &str = concat_lf!("✨\r\n🎉\r");
+    assert_eq!(TEST3, "✨\n🎉\n");
+}
+
+#[test]
+fn concat_c_array_smoke() {
+    use constcat::concat_c_array;
+
+    const TEST0: &str = concat_c_array!();
+    assert_eq!(TEST0, "{}");
+
+    const TEST1: &str = concat_c_array!(&[0x00, 0x0A], &[0xFF],);
+    assert_eq!(TEST1, "{0x00, 0x0a, 0xff}");
+
+    const TEST2: &str = concat_c_array!(radix = dec; &[0, 10, 255]);
+    assert_eq!(TEST2, "{0, 10, 255}");
+
+    const TEST3: &str = concat_c_array!(sep = ","; &[1, 2]);
+    assert_eq!(TEST3, "{0x01,0x02}");
+
+    const SEP: &str = ",\n    ";
+    const TEST4: &str = concat_c_array!(sep = SEP, radix = hex; &[1, 2]);
+    assert_eq!(TEST4, "{0x01,\n    0x02}");
+}
+
+#[test]
+fn concat_bounded_smoke() {
+    use constcat::concat_bounded;
+
+    const TEST0: &[u8] = concat_bounded!([u8]: max = 0x7F;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_bounded!([u8]: max = 0x7F; &[0, 0x7F], &[0x10],);
+    assert_eq!(TEST1, [0, 0x7F, 0x10]);
+
+    const TEST2: &[i16] = concat_bounded!([i16]: min = -5; &[-5, i16::MAX]);
+    assert_eq!(TEST2, [-5, i16::MAX]);
+
+    const TEST3: &[i64] = concat_bounded!([i64]: min = -1, max = 1; &[-1, 0], &[1]);
+    assert_eq!(TEST3, [-1, 0, 1]);
+}
+
+#[test]
+fn concat_range_smoke() {
+    use constcat::concat_range;
+
+    const TEST0: &[i32] = concat_range!([i32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = concat_range!([i32]: 0..5, 10..13,);
+    assert_eq!(TEST1, [0, 1, 2, 3, 4, 10, 11, 12]);
+
+    const TEST2: &[i8] = concat_range!([i8]: -3..=-1, 5..5, 3..=3, 7..2);
+    assert_eq!(TEST2, [-3, -2, -1, 3]);
+
+    const START: u8 = 250;
+    const TEST3: &[u8] = concat_range!([u8]: START..=255, &[1, 2], (START - 250)..2);
+    assert_eq!(TEST3, [250, 251, 252, 253, 254, 255, 1, 2, 0, 1]);
+
+    const TEST4: &[i8] = concat_range!([i8]: -128..=127);
+    assert_eq!(TEST4.len(), 256);
+    assert_eq!(TEST4[0], i8::MIN);
+    assert_eq!(TEST4[255], i8::MAX);
+
+    const TEST5: &[u64] = concat_range!([u64]: &[0; 0], 1..3, [9, 9]);
+    assert_eq!(TEST5, [1, 2, 9, 9]);
+
+    const I128_MAX: i128 = i128::MAX;
+    const TEST6: &[i128] = concat_range!([i128]: (I128_MAX - 1)..=I128_MAX, I128_MAX..=I128_MAX);
+    assert_eq!(TEST6, [i128::MAX - 1, i128::MAX, i128::MAX]);
+
+    const U128_MAX: u128 = u128::MAX;
+    const TEST7: &[u128] = concat_range!([u128]: (U128_MAX - 2)..U128_MAX, U128_MAX..U128_MAX);
+    assert_eq!(TEST7, [u128::MAX - 2, u128::MAX - 1]);
+
+    const TEST8: &[i128] = concat_range!([i128]: (i128::MIN)..=(i128::MIN + 1));
+    assert_eq!(TEST8, [i128::MIN, i128::MIN + 1]);
+}
+
+#[test]
+fn concat_hexdump_smoke() {
+    use constcat::concat_hexdump;
+
+    const TEST0: &str = concat_hexdump!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_hexdump!(b"0123456789abcdef".as_slice(), &[0x00, 0x7F, 0xFF],);
+    assert_eq!(
+        TEST1,
+        "00000000  30 31 32 33 34 35 36 37 38 39 61 62 63 64 65 66  |0123456789abcdef|\n\
+         00000010  00 7f ff                                         |...|\n"
+    );
+
+    const TEST2: &str = concat_hexdump!(width = 2; &[0x41, 0x42]);
+    assert_eq!(TEST2, "00000000  41 42  |AB|\n");
+}
+
+#[test]
+fn concat_slices_opt_smoke() {
+    use constcat::concat_slices_opt;
+
+    const TEST0: &[i32] = concat_slices_opt!([i32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = concat_slices_opt!([i32]: None, None,);
+    assert_eq!(TEST1, []);
+
+    const A: &[i32] = &[1, 2];
+    const MAYBE: Option<&[i32]> = Some(&[3]);
+    const NONE: Option<&[i32]> = None;
+    const TEST2: &[i32] = concat_slices_opt!([i32]: Some(A), MAYBE, NONE, None, Some(&[4, 5]));
+    assert_eq!(TEST2, [1, 2, 3, 4, 5]);
+
+    const TEST3: &[&str] = concat_slices_opt!([&str]: Some(&["a"]), Some(&[]));
+    assert_eq!(TEST3, ["a"]);
+}
+
+#[test]
+fn concat_first_last_smoke() {
+    use constcat::{concat_first, concat_last};
+
+    const A: &[i32] = &[];
+    const B: &[i32] = &[1, 2, 3];
+
+    const TEST0: i32 = concat_first!([i32]: A, B, &[4],);
+    assert_eq!(TEST0, 1);
+
+    const TEST1: i32 = concat_last!([i32]: A, B, &[4], A,);
+    assert_eq!(TEST1, 4);
+
+    const TEST2: &str = concat_first!([&str]: &["one"]);
+    assert_eq!(TEST2, "one");
+
+    const TEST3: (u8, char) = concat_last!([(u8, char)]: &[(1, 'a'), (2, 'b')]);
+    assert_eq!(TEST3, (2, 'b'));
+}
+
+#[test]
+fn concat_rotate_smoke() {
+    use constcat::{concat_rotate_left, concat_rotate_right};
+
+    const TEST0: &[i32] = concat_rotate_left!([i32]: 3;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i32] = concat_rotate_right!([i32]: 3;);
+    assert_eq!(TEST1, []);
+
+    const A: &[i32] = &[1, 2, 3];
+    const B: &[i32] = &[4, 5];
+
+    const TEST2: &[i32] = concat_rotate_left!([i32]: 0; A, B);
+    assert_eq!(TEST2, [1, 2, 3, 4, 5]);
+
+    const TEST3: &[i32] = concat_rotate_left!([i32]: 1; A, B,);
+    assert_eq!(TEST3, [2, 3, 4, 5, 1]);
+
+    const TEST4: &[i32] = concat_rotate_left!([i32]: 12; A, B);
+    assert_eq!(TEST4, [3, 4, 5, 1, 2]);
+
+    const TEST5: &[i32] = concat_rotate_right!([i32]: 1; A, B);
+    assert_eq!(TEST5, [5, 1, 2, 3, 4]);
+
+    const TEST6: &[i32] = concat_rotate_right!([i32]: 5; A, B);
+    assert_eq!(TEST6, [1, 2, 3, 4, 5]);
+
+    const TEST7: &[&str] = concat_rotate_right!([&str]: 6; &["a", "b"], &["c", "d"]);
+    assert_eq!(TEST7, ["c", "d", "a", "b"]);
+}
+
+#[test]
+fn concat_slices_char() {
+    use constcat::concat_slices;
+
+    const A: &[char] = &['\0', 'a', 'é'];
+    const B: &[char] = &['✨', '🎉', char::MAX];
+
+    const TEST0: &[char] = concat_slices!([char]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[char] = concat_slices!([char]: A, B, ['z'; 2]);
+    assert_eq!(TEST1, ['\0', 'a', 'é', '✨', '🎉', char::MAX, 'z', 'z']);
+    assert!(TEST1.iter().all(|c| char::from_u32(*c as u32) == Some(*c)));
+    assert_eq!(TEST1.as_ptr() as usize % core::mem::align_of::<char>(), 0);
+}
+
+#[test]
+fn concat_char_str_smoke() {
+    use constcat::concat_char_str;
+
+    const TEST0: &str = concat_char_str!();
+    assert_eq!(TEST0, "");
+
+    const A: &[char] = &['a', 'é', '✨', '🎉'];
+    const TEST1: &str = concat_char_str!(A, ['!'], &[char::MAX],);
+    assert_eq!(TEST1, "aé✨🎉!\u{10FFFF}");
+}
+
+#[test]
+fn concat_check_smoke() {
+    use constcat::{concat, concat_check, concat_slices};
+
+    const fn non_empty(s: &[i32]) -> bool {
+        !s.is_empty()
+    }
+    const fn starts_with_hash(s: &str) -> bool {
+        s.as_bytes()[0] == b'#'
+    }
+
+    const TEST0: &[i32] = concat_check!(concat_slices!([i32]: &[1], &[2]), non_empty);
+    assert_eq!(TEST0, [1, 2]);
+
+    const TEST1: &str = concat_check!(concat!("#", "fff"), starts_with_hash,);
+    assert_eq!(TEST1, "#fff");
+}
+
+#[test]
+fn assert_palindrome_smoke() {
+    use constcat::{assert_palindrome, concat, concat_slices};
+
+    assert_palindrome!(concat_slices!([i32]:));
+    assert_palindrome!(concat_slices!([i32]: &[1, 2], &[3], &[2, 1]));
+    assert_palindrome!(concat_slices!([char]: &['a', 'b'], &['b', 'a']),);
+    assert_palindrome!(concat!("race", "car").as_bytes());
+}
+
+#[test]
+fn concat_min_max_smoke() {
+    use constcat::{concat_max, concat_min};
+
+    const A: &[i32] = &[5, -3, 8];
+    const B: &[i32] = &[0, 12, -3];
+
+    const TEST0: i32 = concat_min!([i32]: A, B,);
+    assert_eq!(TEST0, -3);
+
+    const TEST1: i32 = concat_max!([i32]: A, B,);
+    assert_eq!(TEST1, 12);
+
+    const TEST2: u8 = concat_min!([u8]: &[7]);
+    assert_eq!(TEST2, 7);
+
+    const TEST3: char = concat_max!([char]: &['a', 'z'], &['m']);
+    assert_eq!(TEST3, 'z');
+
+    const TEST4: u64 = concat_max!([u64]: &[u64::MAX], &[0]);
+    assert_eq!(TEST4, u64::MAX);
+}
+
+#[test]
+fn concat_encode_smoke() {
+    use constcat::concat_encode;
+
+    const TEST0: &[u8] = concat_encode!(encoding = latin1;);
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = concat_encode!(encoding = ascii; "test", 10, 'b', true,);
+    assert_eq!(TEST1, b"test10btrue");
+
+    const TEST2: &[u8] = concat_encode!(encoding = latin1; "Café", " ÿ", '\u{80}');
+    assert_eq!(TEST2, b"Caf\xE9 \xFF\x80");
+
+    const TEST3: &[u8] = concat_encode!(encoding = utf8; "Café ", "🎉");
+    assert_eq!(TEST3, "Café 🎉".as_bytes());
+}
+
+#[test]
+fn concat_grid_smoke() {
+    use constcat::concat_grid;
+
+    const TEST0: &[[i32; 2]; 0] = concat_grid!([i32]: cols = 2;);
+    assert_eq!(TEST0, &[] as &[[i32; 2]; 0]);
+
+    const COLS: usize = 2;
+    const TEST1: &[[i32; COLS]; 3] = concat_grid!([i32]: cols = COLS; &[1, 2, 3], &[4, 5, 6],);
+    assert_eq!(TEST1, &[[1, 2], [3, 4], [5, 6]]);
+
+    const TEST2: &[[char; 1]; 2] = concat_grid!([char]: cols = 1; &['a'], &['b']);
+    assert_eq!(TEST2, &[['a'], ['b']]);
+}
+
+#[test]
+fn concat_base32_smoke() {
+    use constcat::concat_base32;
+
+    // Test vectors from RFC 4648 section 10
+    const TEST0: &str = concat_base32!();
+    assert_eq!(TEST0, "");
+    const TEST1: &str = concat_base32!(b"f".as_slice());
+    assert_eq!(TEST1, "MY======");
+    const TEST2: &str = concat_base32!(b"f".as_slice(), b"o".as_slice());
+    assert_eq!(TEST2, "MZXQ====");
+    const TEST3: &str = concat_base32!(b"foo".as_slice());
+    assert_eq!(TEST3, "MZXW6===");
+    const TEST4: &str = concat_base32!(b"foob".as_slice());
+    assert_eq!(TEST4, "MZXW6YQ=");
+    const TEST5: &str = concat_base32!(b"fooba".as_slice());
+    assert_eq!(TEST5, "MZXW6YTB");
+    const TEST6: &str = concat_base32!(b"foo".as_slice(), b"bar".as_slice(),);
+    assert_eq!(TEST6, "MZXW6YTBOI======");
+
+    const TEST7: &str = concat_base32!(pad = false; b"foob".as_slice());
+    assert_eq!(TEST7, "MZXW6YQ");
+    const TEST8: &str = concat_base32!(pad = false; &[0xFF; 5]);
+    assert_eq!(TEST8, "77777777");
+}
+
+#[test]
+fn concat_bytes_debug_smoke() {
+    use constcat::concat_bytes_debug;
+
+    const TEST0: (&[u8], &str) = concat_bytes_debug!();
+    assert_eq!(TEST0, (&[][..], ""));
+
+    const A: &[u8] = &[1, 2];
+    const TEST1: (&[u8], &str) = concat_bytes_debug!(A, &[3], &[0; 0],);
+    assert_eq!(TEST1.0, [1, 2, 3]);
+    if cfg!(feature = "debug_layout") {
+        assert_eq!(
+            TEST1.1,
+            "A @ 0..2 (2 bytes)\n&[3] @ 2..3 (1 byte)\n&[0; 0] @ 3..3 (0 bytes)"
+        );
+    } else {
+        assert_eq!(TEST1.1, "");
+    }
+}
+
+#[test]
+fn concat_bytes_aligned_for_smoke() {
+    use constcat::concat_bytes_aligned_for;
+
+    const TEST0: &[u8] = concat_bytes_aligned_for!(u64;);
+    assert_eq!(TEST0, b"");
+
+    const TEST1: &[u8] = concat_bytes_aligned_for!(u16; &[1, 2, 3], &[4],);
+    assert_eq!(TEST1, [1, 2, 3, 4]);
+
+    #[repr(C)]
+    struct Record(u32, u8);
+    const TEST2: &[u8] = concat_bytes_aligned_for!(Record; &[0; 8], &[1; 8]);
+    assert_eq!(TEST2.len(), 16);
+
+    const TEST3: &[u8] = concat_bytes_aligned_for!((); &[1, 2, 3]);
+    assert_eq!(TEST3, [1, 2, 3]);
+}
+
+#[test]
+fn concat_bytes_sha256_smoke() {
+    use constcat::concat_bytes_sha256;
+
+    const TEST0: (&[u8], &str) = concat_bytes_sha256!();
+    assert_eq!(TEST0.0, b"");
+    assert_eq!(
+        TEST0.1,
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+
+    const QUICK: &[u8] = b"The quick brown fox ";
+    const LAZY: &[u8] = b"jumps over the lazy dog";
+    const TEST1: (&[u8], &str) = concat_bytes_sha256!(QUICK, LAZY,);
+    assert_eq!(TEST1.0, b"The quick brown fox jumps over the lazy dog");
+    assert_eq!(
+        TEST1.1,
+        "d7a8fbb307d7809469ca9abcb0082e4f8d5651e46d3cdb762d02d0bf37c9e592"
+    );
+
+    // Lengths around the block boundary where the padding spills over.
+    const TEST2: (&[u8], &str) = concat_bytes_sha256!(&[b'a'; 55]);
+    assert_eq!(
+        TEST2.1,
+        "9f4390f8d30c2dd92ec9f095b65e2b9ae9b0a925a5258e241c9f1e910f734318"
+    );
+    const TEST3: (&[u8], &str) = concat_bytes_sha256!(&[b'a'; 56]);
+    assert_eq!(
+        TEST3.1,
+        "b35439a4ac6f0948b6d6f9e3c6af0f5f590ce20f1bde7090ef7970686ec6738a"
+    );
+    const TEST4: (&[u8], &str) = concat_bytes_sha256!(&[b'a'; 64], &[b'a'; 64]);
+    assert_eq!(
+        TEST4.1,
+        "6836cf13bac400e9105071cd6af47084dfacad4e5e302c94bfed24e013afb73e"
+    );
+}
+
+#[test]
+fn concat_strip_bom_smoke() {
+    use constcat::{concat_no_bom, concat_strip_bom};
+
+    const TEST0: &str = concat_strip_bom!();
+    assert_eq!(TEST0, "");
+
+    const BOM: &str = "\u{FEFF}";
+    const TEST1: &str = concat_strip_bom!(BOM, "\u{FEFF}a", "b\u{FEFF}", "\u{FEFF}\u{FEFF}c",);
+    assert_eq!(TEST1, "ab\u{FEFF}\u{FEFF}c");
+
+    const TEST2: &str = concat_strip_bom!("plain", 1, 'x');
+    assert_eq!(TEST2, "plain1x");
+
+    const TEST3: &str = concat_no_bom!(BOM, "a", "b\u{FEFF}");
+    assert_eq!(TEST3, "\u{FEFF}ab\u{FEFF}");
+}
+
+#[test]
+fn concat_flatten_options_smoke() {
+    use constcat::concat_flatten_options;
+
+    const TEST0: &[u8] = concat_flatten_options!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const NONE: &[Option<u8>] = &[None, None];
+    const TEST1: &[u8] = concat_flatten_options!([u8]: NONE, NONE,);
+    assert_eq!(TEST1, []);
+
+    const TEST2: &[char] =
+        concat_flatten_options!([char]: [Some('a'), None], &[None, Some('b'), Some('c')]);
+    assert_eq!(TEST2, ['a', 'b', 'c']);
+
+    const NONE_STR: &[Option<&str>] = &[None];
+    const TEST3: &[&str] = concat_flatten_options!([&str]: &[Some("x")], NONE_STR);
+    assert_eq!(TEST3, ["x"]);
+}
+
+#[test]
+fn concat_spanned_smoke() {
+    use constcat::concat_spanned;
+
+    const TEST0: (&str, &[(usize, usize)]) = concat_spanned!();
+    assert_eq!(TEST0, ("", [].as_slice()));
+
+    const EMPTY: &str = "";
+    const TEST1: (&str, &[(usize, usize)]) = concat_spanned!("é", EMPTY, 42, 'x',);
+    assert_eq!(TEST1.0, "é42x");
+    assert_eq!(TEST1.1, [(0, 2), (2, 2), (2, 4), (4, 5)]);
+
+    let (s, spans) = TEST1;
+    assert_eq!(&s[spans[2].0..spans[2].1], "42");
+}
+
+#[test]
+fn concat_urlencode_smoke() {
+    use constcat::concat_urlencode;
+
+    const TEST0: &str = concat_urlencode!();
+    assert_eq!(TEST0, "");
+
+    const UNRESERVED: &str = "AZaz09-._~";
+    const TEST1: &str = concat_urlencode!(UNRESERVED, "",);
+    assert_eq!(TEST1, UNRESERVED);
+
+    const TEST2: &str = concat_urlencode!("a+b?c#d", '€', 100);
+    assert_eq!(TEST2, "a%2Bb%3Fc%23d%E2%82%AC100");
+
+    const TEST3: &str = concat_urlencode!("%\n");
+    assert_eq!(TEST3, "%25%0A");
+}
+
+#[test]
+fn concat_html_escape_smoke() {
+    use constcat::concat_html_escape;
+
+    const TEST0: &str = concat_html_escape!();
+    assert_eq!(TEST0, "");
+
+    const PLAIN: &str = "plain text, ünïcode";
+    const TEST1: &str = concat_html_escape!(PLAIN,);
+    assert_eq!(TEST1, PLAIN);
+
+    const TEST2: &str = concat_html_escape!("<a href=\"", '&', "'>", "&amp;");
+    assert_eq!(TEST2, "&lt;a href=&quot;&amp;&#39;&gt;&amp;amp;");
+}
+
+#[test]
+fn concat_distinct_smoke() {
+    use constcat::concat_distinct;
+
+    const TEST0: &[u8] = concat_distinct!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const EMPTY: &[u8] = &[];
+    const TEST1: &[u8] = concat_distinct!([u8]: EMPTY, &[1, 2], EMPTY, &[1], &[1, 3],);
+    assert_eq!(TEST1, [1, 2, 1, 1, 3]);
+
+    const TEST2: &[char] = concat_distinct!([char]: ['a', 'b'], ['b', 'a']);
+    assert_eq!(TEST2, ['a', 'b', 'b', 'a']);
+}
+
+#[test]
+fn concat_chunks_smoke() {
+    use constcat::concat_chunks;
+
+    const TEST0: &[[u8; 4]] = concat_chunks!([u8]: size = 4;);
+    assert_eq!(TEST0, [[0u8; 4]; 0]);
+
+    const SIZE: usize = 3;
+    const TEST1: &[[char; SIZE]] =
+        concat_chunks!([char]: size = SIZE; &['a', 'b'], &['c', 'd', 'e', 'f'],);
+    assert_eq!(TEST1, [['a', 'b', 'c'], ['d', 'e', 'f']]);
+    assert_eq!(TEST1.len(), 2);
+}
+
+#[test]
+fn concat_bytes_adler32_smoke() {
+    use constcat::concat_bytes_adler32;
+
+    const TEST0: &[u8] = concat_bytes_adler32!();
+    assert_eq!(TEST0, [0, 0, 0, 1]);
+
+    const ABC: &[u8] = b"abc";
+    const TEST1: &[u8] = concat_bytes_adler32!(ABC,);
+    assert_eq!(TEST1, b"abc\x02\x4d\x01\x27");
+
+    // Long enough for both sums to wrap around the modulus.
+    const TEST2: &[u8] = concat_bytes_adler32!(&[0xFF; 4000], &[0xFF; 2000]);
+    assert_eq!(TEST2.len(), 6004);
+    assert_eq!(&TEST2[6000..], 0xA49759EAu32.to_be_bytes());
+}
+
+#[test]
+fn concat_ident_str_smoke() {
+    use constcat::concat_ident_str;
+
+    const TEST0: &str = concat_ident_str!("_");
+    assert_eq!(TEST0, "_");
+
+    const TEST1: &str = concat_ident_str!("", "x", 0, "_Y",);
+    assert_eq!(TEST1, "x0_Y");
+
+    const TEST2: &str = concat_ident_str!("__", 123);
+    assert_eq!(TEST2, "__123");
+}
+
+#[test]
+fn concat_unique_smoke() {
+    use constcat::concat_unique;
+
+    const TEST0: &[u8] = concat_unique!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[char] = concat_unique!([char]: &['b', 'a', 'b'], ['c', 'a'], &['a'],);
+    assert_eq!(TEST1, ['b', 'a', 'c']);
+
+    const TEST2: &[i32] = concat_unique!([i32]: &[7, 7, 7, 7]);
+    assert_eq!(TEST2, [7]);
+}
+
+#[test]
+fn concat_bytes_bswap_smoke() {
+    use constcat::concat_bytes_bswap;
+
+    const TEST0: &[u8] = concat_bytes_bswap!(width = 4;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_bytes_bswap!(width = 1; &[1, 2, 3],);
+    assert_eq!(TEST1, [1, 2, 3]);
+
+    const WORDS: &[u8] = &[1, 2, 3, 4, 5, 6];
+    const TEST2: &[u8] = concat_bytes_bswap!(width = 4; WORDS, &[7, 8]);
+    assert_eq!(TEST2, [4, 3, 2, 1, 8, 7, 6, 5]);
+}
+
+#[test]
+fn concat_justify_smoke() {
+    use constcat::concat_justify;
+
+    const TEST0: &str = concat_justify!(width = 0;);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_justify!(width = 3, fill = '-';);
+    assert_eq!(TEST1, "---");
+
+    const TEST2: &str = concat_justify!(width = 5, align = left; "né", 1,);
+    assert_eq!(TEST2, "né1 ");
+
+    const WIDTH: usize = 6;
+    const TEST3: &str = concat_justify!(width = WIDTH, align = right, fill = '0'; 42);
+    assert_eq!(TEST3, "000042");
+
+    const TEST4: &str = concat_justify!(width = 2, align = right; "ab");
+    assert_eq!(TEST4, "ab");
+}
+
+#[test]
+fn concat_split_at_smoke() {
+    use constcat::concat_split_at;
+
+    const TEST0: (&[u8], &[u8]) = concat_split_at!([u8]: 0;);
+    assert_eq!(TEST0, ([].as_slice(), [].as_slice()));
+
+    const TEST1: (&[char], &[char]) = concat_split_at!([char]: 3; &['a', 'b'], ['c'],);
+    assert_eq!(TEST1, (['a', 'b', 'c'].as_slice(), [].as_slice()));
+
+    const MID: usize = 1;
+    const TEST2: (&[i32], &[i32]) = concat_split_at!([i32]: MID + 1; &[1], &[2, 3], &[4]);
+    assert_eq!(TEST2, ([1, 2].as_slice(), [3, 4].as_slice()));
+}
+
+#[test]
+fn concat_debug_decimal_smoke() {
+    use constcat::concat_debug_decimal;
+
+    const TEST0: &str = concat_debug_decimal!([u8]:);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_debug_decimal!([i64]: &[i64::MIN], [0, i64::MAX],);
+    assert_eq!(TEST1, "-9223372036854775808, 0, 9223372036854775807");
+
+    const SEP: &str = "|";
+    const TEST2: &str = concat_debug_decimal!([u128]: sep = SEP; &[u128::MAX], &[7]);
+    assert_eq!(TEST2, "340282366920938463463374607431768211455|7");
+}
+
+#[test]
+fn concat_str_slices_smoke() {
+    use constcat::concat_str_slices;
+
+    const TEST0: &[&str] = concat_str_slices!();
+    assert_eq!(TEST0, [""; 0]);
+
+    const EMPTY: [&str; 0] = [];
+    const TEST1: &[&str] = concat_str_slices!(EMPTY, ["a", ""], &["a"],);
+    assert_eq!(TEST1, ["a", "", "a"]);
+}
+
+#[test]
+fn concat_strip_prefix_smoke() {
+    use constcat::{concat_strip_prefix, concat_strip_suffix};
+
+    const TEST0: &str = concat_strip_prefix!("x";);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_strip_prefix!("ab"; "a", "b", "ab",);
+    assert_eq!(TEST1, "ab");
+
+    const TEST2: &str = concat_strip_prefix!(""; "ä");
+    assert_eq!(TEST2, "ä");
+
+    const PAT: &str = "ä";
+    const TEST3: &str = concat_strip_suffix!(PAT; "ää");
+    assert_eq!(TEST3, "ä");
+
+    const TEST4: &str = concat_strip_suffix!("longer"; "long");
+    assert_eq!(TEST4, "long");
+
+    const TEST5: &str = concat_strip_suffix!(".rs"; "lib", ".rs", ".txt");
+    assert_eq!(TEST5, "lib.rs.txt");
+}
+
+#[test]
+fn concat_parity_smoke() {
+    use constcat::concat_parity;
+
+    const TEST0: &[u8] = concat_parity!([u8]: even;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[char] = concat_parity!([char]: odd; &['a', 'b'], ['c'],);
+    assert_eq!(TEST1, ['a', 'b', 'c']);
+
+    const TEST2: &[i32] = concat_parity!([i32]: even; &[1], &[2, 3], &[4]);
+    assert_eq!(TEST2, [1, 2, 3, 4]);
+}
+
+#[test]
+fn concat_wrap_smoke() {
+    use constcat::{concat_bytes_wrap, concat_wrap};
+
+    const TEST0: &str = concat_wrap!(open = "(", close = ")";);
+    assert_eq!(TEST0, "()");
+
+    const QUOTE: &str = "\"";
+    const TEST1: &str = concat_wrap!(open = QUOTE, close = QUOTE; "a", 1, 'b',);
+    assert_eq!(TEST1, "\"a1b\"");
+
+    const EMPTY: &[u8] = &[];
+    const TEST2: &[u8] = concat_bytes_wrap!(open = EMPTY, close = &[0];);
+    assert_eq!(TEST2, [0]);
+
+    const TEST3: &[u8] = concat_bytes_wrap!(open = &[1, 2], close = &[5]; &[3], &[4],);
+    assert_eq!(TEST3, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn concat_offset_table_smoke() {
+    use constcat::concat_offset_table;
+
+    const TEST0: &[u8] = concat_offset_table!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_offset_table!((&[1, 2], 0), (&[3], 2),);
+    assert_eq!(TEST1, [1, 2, 3]);
+
+    const EMPTY: &[u8] = &[];
+    const TEST2: &[u8] = concat_offset_table!((&[1], 1), (EMPTY, 4));
+    assert_eq!(TEST2, [0, 1, 0, 0]);
+}
+
+#[test]
+fn concat_len_str_smoke() {
+    use constcat::concat_len_str;
+
+    const TEST0: &str = concat_len_str!([u8]:);
+    assert_eq!(TEST0, "0");
+
+    const TEST1: &str = concat_len_str!([u32]: &[1, 2, 3], [4; 1000],);
+    assert_eq!(TEST1, "1003");
+}
+
+#[test]
+fn concat_utf8_aligned_smoke() {
+    use constcat::concat_utf8_aligned;
+
+    const TEST0: &str = concat_utf8_aligned!([u8]:);
+    assert_eq!(TEST0, "");
+
+    const EMPTY: &[u8] = &[];
+    const TEST1: &str =
+        concat_utf8_aligned!([u8]: "€".as_bytes(), EMPTY, *b"!", &[0xF0, 0x9F, 0xA6, 0x80],);
+    assert_eq!(TEST1, "€!🦀");
+}
+
+#[test]
+fn concat_rle_smoke() {
+    use constcat::{concat_rle, concat_rle_decode};
+
+    const TEST0: &[u8] = concat_rle!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_rle!(&[1, 1], &[1, 2], &[3, 3],);
+    assert_eq!(TEST1, [3, 1, 1, 2, 2, 3]);
+
+    const TEST2: &[u8] = concat_rle!(&[9; 510]);
+    assert_eq!(TEST2, [255, 9, 255, 9]);
+
+    const TEST3: &[u8] = concat_rle_decode!(TEST1, &[0, 42], &[2, 4],);
+    assert_eq!(TEST3, [1, 1, 1, 2, 3, 3, 4, 4]);
+
+    const TEST4: &[u8] = concat_rle_decode!(TEST2);
+    assert_eq!(TEST4, [9; 510]);
+
+    const TEST5: &[u8] = concat_rle_decode!();
+    assert_eq!(TEST5, []);
+}
+
+#[test]
+fn concat_maxline_smoke() {
+    use constcat::concat_maxline;
+
+    const TEST0: &str = concat_maxline!(width = 0;);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_maxline!(width = 0; "\n", "\n\n",);
+    assert_eq!(TEST1, "\n\n\n");
+
+    const TEST2: &str = concat_maxline!(width = 3; "ab", "c\nd", "ef\n");
+    assert_eq!(TEST2, "abc\ndef\n");
+}
+
+#[test]
+fn concat_chunked_len_smoke() {
+    use constcat::concat_chunked_len;
+
+    const TEST0: (&[u8], usize) = concat_chunked_len!([u8]: size = 3;);
+    assert_eq!(TEST0, ([].as_slice(), 0));
+
+    const TEST1: (&[char], usize) = concat_chunked_len!([char]: size = 1; &['a'], ['b', 'c'],);
+    assert_eq!(TEST1, (['a', 'b', 'c'].as_slice(), 3));
+}
+
+#[test]
+fn export_concat_bytes_smoke() {
+    use constcat::export_concat_bytes;
+
+    const PART: &[u8] = &[3, 4];
+
+    export_concat_bytes!(constcat_smoke_empty,);
+    assert_eq!(constcat_smoke_empty, []);
+
+    export_concat_bytes!(constcat_smoke_ident, &[1, 2], PART,);
+    assert_eq!(constcat_smoke_ident, [1, 2, 3, 4]);
+
+    export_concat_bytes!("constcat_smoke_literal", PART, &[5]);
+    extern "C" {
+        static constcat_smoke_literal: [u8; 3];
+    }
+    // SAFETY: The symbol is defined above with the same type.
+    assert_eq!(unsafe { constcat_smoke_literal }, [3, 4, 5]);
+}
+
+#[test]
+fn concat_mode_smoke() {
+    use constcat::concat_mode;
+
+    const TEST0: char = concat_mode!([char]: ['x'],);
+    assert_eq!(TEST0, 'x');
+
+    const TEST1: i32 = concat_mode!([i32]: &[1, 2], &[2, 1]);
+    assert_eq!(TEST1, 1);
+
+    const TEST2: i32 = concat_mode!([i32]: &[1, 2, 3], &[3, 2, 3]);
+    assert_eq!(TEST2, 3);
+}
+
+#[test]
+fn concat_assert_golden_smoke() {
+    use constcat::concat_assert_golden;
+
+    const MAGIC: &[u8] = b"GOLD";
+    const TEST0: &[u8] = concat_assert_golden!("golden.bin", MAGIC, &[0, 1],);
+    assert_eq!(TEST0, b"GOLD\x00\x01");
+}
+
+#[test]
+fn concat_template_smoke() {
+    use constcat::concat_template;
+
+    const TEST0: &str = concat_template!("");
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_template!("no placeholders {{}}", unused = 1,);
+    assert_eq!(TEST1, "no placeholders {}");
+
+    const NAME: &str = "crab";
+    const TEST2: &str = concat_template!("{a}{b}{a} ü{c}", a = NAME, b = '🦀', c = true);
+    assert_eq!(TEST2, "crab🦀crab ütrue");
+}
+
+#[test]
+fn concat_sum_smoke() {
+    use constcat::{concat_product, concat_sum};
+
+    const TEST0: i32 = concat_sum!([i32]:);
+    assert_eq!(TEST0, 0);
+
+    const TEST1: i8 = concat_sum!([i8]: &[-128, 127], [1],);
+    assert_eq!(TEST1, 0);
+
+    const TEST2: u8 = concat_product!([u8]:);
+    assert_eq!(TEST2, 1);
+
+    const TEST3: i64 = concat_product!([i64]: &[-2, 3], &[0, i64::MAX]);
+    assert_eq!(TEST3, 0);
+
+    const TEST4: u128 = concat_product!([u128]: &[u64::MAX as u128], &[u64::MAX as u128]);
+    assert_eq!(TEST4, (u64::MAX as u128) * (u64::MAX as u128));
+}
+
+#[test]
+fn concat_argsort_smoke() {
+    use constcat::concat_argsort;
+
+    const TEST0: &[usize] = concat_argsort!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[usize] = concat_argsort!([char]: ['c', 'a'], &['b', 'a'], &['a'],);
+    assert_eq!(TEST1, [1, 3, 4, 2, 0]);
+
+    const TEST2: &[usize] = concat_argsort!([i32]: &[-1, 0, 1]);
+    assert_eq!(TEST2, [0, 1, 2]);
+}
+
+#[test]
+fn concat_regions_smoke() {
+    use constcat::concat_regions;
+
+    const TEST0: &[u8] = concat_regions!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_regions!((64, 8), (0, 16), (16, 32), (20, 0),);
+    assert_eq!(TEST1.len(), 64);
+    assert_eq!(TEST1[16..24], [0; 8]);
+    assert_eq!(TEST1[24], 16);
+    assert_eq!(TEST1[0], 64);
+    assert_eq!(TEST1[8], 8);
+    assert_eq!(TEST1[32], 16);
+    assert_eq!(TEST1[40], 32);
+    assert_eq!(TEST1[48], 20);
+
+    const TEST2: &[u8] = concat_regions!((u64::MAX, 0));
+    assert_eq!(TEST2[..8], [0xFF; 8]);
+}
+
+#[test]
+fn concat_json_numbers_smoke() {
+    use constcat::concat_json_numbers;
+
+    const TEST0: &str = concat_json_numbers!([u8]:);
+    assert_eq!(TEST0, "[]");
+
+    const TEST1: &str = concat_json_numbers!([i16]: &[i16::MIN], [0], &[i16::MAX],);
+    assert_eq!(TEST1, "[-32768,0,32767]");
+}
+
+#[test]
+fn concat_bracketed_magic_smoke() {
+    use constcat::{assert_bracketed, concat_bracketed_magic};
+
+    const MAGIC: &[u8] = &[0x7E];
+    const TEST0: &[u8] = concat_bracketed_magic!(magic = MAGIC;);
+    assert_eq!(TEST0, [0x7E, 0x7E]);
+    assert_bracketed!(TEST0, MAGIC);
+
+    const TEST1: &[u8] = concat_bracketed_magic!(magic = &[1, 2]; &[3], &[4],);
+    assert_eq!(TEST1, [1, 2, 3, 4, 1, 2]);
+    assert_bracketed!(TEST1, &[1, 2],);
+
+    const EMPTY: &[u8] = &[];
+    assert_bracketed!(EMPTY, EMPTY);
+}
+
+#[test]
+fn concat_bytes_require_distinct_count_smoke() {
+    use constcat::concat_bytes_require_distinct_count;
+
+    const TEST0: &[u8] = concat_bytes_require_distinct_count!(min = 0;);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_bytes_require_distinct_count!(min = 3; &[1, 1, 2], &[2, 3],);
+    assert_eq!(TEST1, [1, 1, 2, 2, 3]);
+
+    const ALL: [u8; 256] = {
+        let mut arr = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    };
+    const TEST2: &[u8] = concat_bytes_require_distinct_count!(min = 256; &ALL);
+    assert_eq!(TEST2.len(), 256);
+}
+
+#[test]
+fn concat_expand_tabs_smoke() {
+    use constcat::concat_expand_tabs;
+
+    const TEST0: &str = concat_expand_tabs!(width = 8;);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_expand_tabs!(width = 1; "\t", "a\t",);
+    assert_eq!(TEST1, " a ");
+
+    const TEST2: &str = concat_expand_tabs!(width = 4; "é\t|", "\n\t\t|");
+    assert_eq!(TEST2, "é   |\n        |");
+
+    const TEST3: &str = concat_expand_tabs!(width = 3; "ab", "c\td");
+    assert_eq!(TEST3, "abc   d");
+}
+
+#[test]
+fn concat_typed_smoke() {
+    use constcat::{concat_typed, ConstSlice};
+
+    const TEST0: ConstSlice<u8, 0> = concat_typed!([u8]:);
+    assert!(TEST0.is_empty());
+
+    const TEST1: ConstSlice<char, 3> = concat_typed!([char]: ['a'], &['b', 'c'],);
+    assert_eq!(TEST1.as_slice(), ['a', 'b', 'c']);
+    assert_eq!(TEST1.0, &['a', 'b', 'c']);
+    assert_eq!(TEST1.iter().count(), 3);
+
+    fn len_of<T, const N: usize>(_: ConstSlice<T, N>) -> usize {
+        N
+    }
+    assert_eq!(len_of(concat_typed!([i32]: &[1, 2], &[3, 4])), 4);
+}
+
+#[test]
+fn concat_bytes_crc16_smoke() {
+    use constcat::concat_bytes_crc16;
+
+    const TEST0: &[u8] = concat_bytes_crc16!();
+    assert_eq!(TEST0, [0xFF, 0xFF]);
+
+    const HEAD: &[u8] = b"1234";
+    const TAIL: &[u8] = b"56789";
+    const TEST1: &[u8] = concat_bytes_crc16!(HEAD, TAIL,);
+    assert_eq!(TEST1, b"123456789\x29\xB1");
+
+    // CRC-16/XMODEM
+    const TEST2: &[u8] = concat_bytes_crc16!(init = 0; HEAD, TAIL);
+    assert_eq!(TEST2[9..], [0x31, 0xC3]);
+
+    // CRC-16/UMTS
+    const TEST3: &[u8] = concat_bytes_crc16!(poly = 0x8005, init = 0; HEAD, TAIL);
+    assert_eq!(TEST3[9..], [0xFE, 0xE8]);
+
+    // CRC-16/CMS
+    const TEST4: &[u8] = concat_bytes_crc16!(poly = 0x8005; HEAD, TAIL);
+    assert_eq!(TEST4[9..], [0xAE, 0xE7]);
+}
+
+#[test]
+fn concat_spdx_smoke() {
+    use constcat::concat_spdx;
+
+    const TEST0: &str = concat_spdx!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_spdx!(op = AND; "GPL-2.0+",);
+    assert_eq!(TEST1, "GPL-2.0+");
+
+    const TEST2: &str = concat_spdx!(op = OR; "MIT", "Apache-2.0", "BSD-3-Clause");
+    assert_eq!(TEST2, "MIT OR Apache-2.0 OR BSD-3-Clause");
+}
+
+#[test]
+fn concat_match_glob_smoke() {
+    use constcat::concat_match_glob;
+
+    const TEST0: &str = concat_match_glob!("";);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_match_glob!("*";);
+    assert_eq!(TEST1, "");
+
+    const TEST2: &str = concat_match_glob!("a?c"; "a", 'é', "c",);
+    assert_eq!(TEST2, "aéc");
+
+    const PATTERN: &str = "*ab*ab";
+    const TEST3: &str = concat_match_glob!(PATTERN; "xabyab", "ab");
+    assert_eq!(TEST3, "xabyabab");
+
+    const TEST4: &str = concat_match_glob!("**?"; "ü");
+    assert_eq!(TEST4, "ü");
+}
+
+#[test]
+fn concat_uuid_smoke() {
+    use constcat::concat_uuid;
+
+    const TEST0: &str = concat_uuid!(&[0; 16]);
+    assert_eq!(TEST0, "00000000-0000-0000-0000-000000000000");
+
+    const MAX: [u8; 16] = [0xFF; 16];
+    const TEST1: &str = concat_uuid!(&MAX,);
+    assert_eq!(TEST1, "ffffffff-ffff-ffff-ffff-ffffffffffff");
+
+    const TEST2: &str = concat_uuid!(&0x0123_4567_89ab_cdef_u64.to_be_bytes(), &[0xA5; 8]);
+    assert_eq!(TEST2, "01234567-89ab-cdef-a5a5-a5a5a5a5a5a5");
+}
+
+#[test]
+fn concat_freq_order_smoke() {
+    use constcat::concat_freq_order;
+
+    const TEST0: &[u8] = concat_freq_order!();
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_freq_order!(&[3, 1, 2], &[0xFF, 2],);
+    assert_eq!(TEST1, [2, 1, 3, 0xFF]);
+
+    const TEST2: &[u8] = concat_freq_order!(&[0; 300], &[1; 301]);
+    assert_eq!(TEST2, [1, 0]);
+}
+
+#[test]
+fn concat_wrap_words_smoke() {
+    use constcat::concat_wrap_words;
+
+    const TEST0: &str = concat_wrap_words!(width = 10;);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_wrap_words!(width = 3; "a b c d",);
+    assert_eq!(TEST1, "a b\nc d");
+
+    const TEST2: &str = concat_wrap_words!(width = 4; "tiny enormous", " x");
+    assert_eq!(TEST2, "tiny\nenormous\nx");
+
+    const TEST3: &str = concat_wrap_words!(width = 5; "one\n", "two three\n");
+    assert_eq!(TEST3, "one\ntwo\nthree\n");
+
+    const TEST4: &str = concat_wrap_words!(width = 80; "keep  double spaces");
+    assert_eq!(TEST4, "keep  double spaces");
+}
+
+#[test]
+fn concat_gen_smoke() {
+    use constcat::concat_gen;
+
+    const fn ident(i: usize) -> usize {
+        i
+    }
+    const TEST0: &[usize] = concat_gen!([usize]: 3..3 => ident);
+    assert_eq!(TEST0, []);
+
+    const START: usize = 2;
+    const TEST1: &[usize] = concat_gen!([usize]: START..START + 3 => ident);
+    assert_eq!(TEST1, [2, 3, 4]);
+
+    const fn bits(i: usize) -> [bool; 2] {
+        [i & 2 != 0, i & 1 != 0]
+    }
+    const TEST2: &[bool] = concat_gen!([bool]: 0..4 => flatten bits);
+    assert_eq!(TEST2, [false, false, false, true, true, false, true, true]);
+
+    const fn reverse_bits(i: usize) -> u8 {
+        (i as u8).reverse_bits() >> 4
+    }
+    const TEST3: &[u8] = concat_gen!([u8]: 0..16 => reverse_bits);
+    assert_eq!(
+        TEST3,
+        [0, 8, 4, 12, 2, 10, 6, 14, 1, 9, 5, 13, 3, 11, 7, 15]
+    );
+
+    const fn parts(i: usize) -> &'static [u8] {
+        const TABLE: [&[u8]; 4] = [&[], &[1, 2], &[], &[3]];
+        TABLE[i]
+    }
+    const TEST4: &[u8] = concat_gen!([u8]: 0..4 => flatten parts);
+    assert_eq!(TEST4, [1, 2, 3]);
+
+    const TEST5: &[u8] = concat_gen!([u8]: 4..4 => flatten parts);
+    assert_eq!(TEST5, []);
+}
+
+#[test]
+fn concat_roundtrip_smoke() {
+    use constcat::concat_roundtrip;
+
+    const fn identity(src: &[u8]) -> &[u8] {
+        src
+    }
+    const TEST0: &[u8] = concat_roundtrip!(identity, identity;);
+    assert_eq!(TEST0, []);
+
+    const fn reverse(src: &[u8]) -> [u8; 3] {
+        [src[2], src[1], src[0]]
+    }
+    const TEST1: &[u8] = concat_roundtrip!(reverse, reverse; &[1], &[2, 3],);
+    assert_eq!(TEST1, [1, 2, 3]);
+}
+
+#[test]
+fn concat_prefix_sums_smoke() {
+    use constcat::concat_prefix_sums;
+
+    const TEST0: &[u32] = concat_prefix_sums!([u32]:);
+    assert_eq!(TEST0, []);
+
+    const LENS: &[usize] = &[2, 0, 5];
+    const TEST1: &[usize] = concat_prefix_sums!([usize]: LENS, &[1],);
+    assert_eq!(TEST1, [2, 2, 7, 8]);
+
+    const TEST2: &[i8] = concat_prefix_sums!([i8]: &[-100, -28], &[127]);
+    assert_eq!(TEST2, [-100, -128, -1]);
+}
+
+#[test]
+fn concat_numbered_smoke() {
+    use constcat::concat_numbered;
+
+    const TEST0: &str = concat_numbered!();
+    assert_eq!(TEST0, "");
+
+    const A: &str = "alpha";
+    const TEST1: &str = concat_numbered!(start = 9; A, "beta",);
+    assert_eq!(TEST1, "9. alpha\n10. beta");
+
+    const TEST2: &str = concat_numbered!(sep = ": "; A);
+    assert_eq!(TEST2, "1: alpha");
+
+    const TEST3: &str = concat_numbered!(join = ", "; A, A);
+    assert_eq!(TEST3, "1. alpha, 2. alpha");
+}
+
+#[test]
+fn concat_require_subsequence_smoke() {
+    use constcat::concat_require_subsequence;
+
+    const EMPTY: &[u8] = &[];
+    const TEST0: &[u8] = concat_require_subsequence!(EMPTY;);
+    assert_eq!(TEST0, []);
+
+    const NEEDLE: &[u8] = &[1, 1, 2];
+    const TEST1: &[u8] = concat_require_subsequence!(NEEDLE; &[0, 1], &[3, 1, 2],);
+    assert_eq!(TEST1, [0, 1, 3, 1, 2]);
+}
+
+#[test]
+fn concat_encode_table_smoke() {
+    use constcat::concat_encode_table;
+
+    const BITS: &[&str; 256] = &{
+        let mut table = ["?"; 256];
+        table[0] = "zero";
+        table[1] = "one";
+        table
+    };
+
+    const TEST0: &str = concat_encode_table!(table = BITS;);
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_encode_table!(table = BITS; &[1, 0], &[7],);
+    assert_eq!(TEST1, "onezero?");
+
+    const TEST2: &str = concat_encode_table!(table = BITS, sep = "-"; &[0, 1]);
+    assert_eq!(TEST2, "zero-one");
+}
+
+#[test]
+fn concat_bytes_xorfold_smoke() {
+    use constcat::concat_bytes_xorfold;
+
+    const TEST0: &[u8; 2] = concat_bytes_xorfold!(width = 2;);
+    assert_eq!(TEST0, &[0, 0]);
+
+    const TEST1: &[u8; 1] = concat_bytes_xorfold!(width = 1; &[0b1100], &[0b1010],);
+    assert_eq!(TEST1, &[0b0110]);
+
+    const TEST2: &[u8; 3] = concat_bytes_xorfold!(width = 3; &[1, 2, 3, 4, 5, 6, 7]);
+    assert_eq!(TEST2, &[1 ^ 4 ^ 7, 2 ^ 5, 3 ^ 6]);
+}
+
+#[test]
+fn concat_colored_smoke() {
+    use constcat::concat_colored;
+
+    const TEST0: &str = concat_colored!();
+    assert_eq!(TEST0, "");
+
+    const A: &str = "warn";
+    const TEST1: &str = concat_colored!((A, 33), (1, 0),);
+    assert_eq!(TEST1, "\x1b[33mwarn\x1b[0m\x1b[0m1\x1b[0m");
+}
+
+#[test]
+fn concat_strip_ansi_smoke() {
+    use constcat::concat_strip_ansi;
+
+    const TEST0: &str = concat_strip_ansi!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_strip_ansi!("\x1b[1;31mé\x1b[0m", " \x1b[", "2K!",);
+    assert_eq!(TEST1, "é !");
+
+    const TEST2: &str = concat_strip_ansi!("\x1b", "x\x1b[1é");
+    assert_eq!(TEST2, "\x1bx\x1b[1é");
+}
+
+#[test]
+fn dot_smoke() {
+    use constcat::dot;
+
+    const EMPTY: &[u8] = &[];
+    const TEST0: u8 = dot!([u8]: EMPTY, EMPTY);
+    assert_eq!(TEST0, 0);
+
+    const A: &[u64] = &[1, 2, 3];
+    const TEST1: u64 = dot!([u64]: A, A,);
+    assert_eq!(TEST1, 14);
+
+    const TEST2: i8 = dot!([i8]: &[-1, 127], &[1, 1]);
+    assert_eq!(TEST2, 126);
+}
+
+#[test]
+fn concat_ipv4_smoke() {
+    use constcat::concat_ipv4;
+
+    const TEST0: &str = concat_ipv4!(0, 0, 0, 0);
+    assert_eq!(TEST0, "0.0.0.0");
+
+    const LAST: u16 = 255;
+    const TEST1: &str = concat_ipv4!(127, 0, 0x00, LAST,);
+    assert_eq!(TEST1, "127.0.0.255");
+}
+
+#[test]
+fn concat_ipv6_smoke() {
+    use constcat::concat_ipv6;
+
+    const TEST0: &str = concat_ipv6!(0, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(TEST0, "::");
+
+    const TEST1: &str = concat_ipv6!(0, 0, 0, 0, 0, 0, 0, 1);
+    assert_eq!(TEST1, "::1");
+
+    const TEST2: &str = concat_ipv6!(0xFE80, 0, 0, 0, 0, 0, 0, 0);
+    assert_eq!(TEST2, "fe80::");
+
+    const TEST3: &str = concat_ipv6!(1, 0, 2, 3, 4, 5, 6, 7,);
+    assert_eq!(TEST3, "1:0:2:3:4:5:6:7");
+
+    const TEST4: &str = concat_ipv6!(1, 0, 0, 2, 0, 0, 3, 0xABCD);
+    assert_eq!(TEST4, "1::2:0:0:3:abcd");
+
+    const TEST5: &str = concat_ipv6!(1, 0, 0, 2, 0, 0, 0, 0x0FFF);
+    assert_eq!(TEST5, "1:0:0:2::fff");
+}
+
+#[test]
+fn assert_semver_ge_smoke() {
+    use constcat::{assert_semver_ge, concat};
+
+    assert_semver_ge!("1.2.0", "1.2");
+    assert_semver_ge!("1.2", "1.2.0",);
+    assert_semver_ge!("2", "1.99.99");
+    assert_semver_ge!("0.10.0", "0.9.1");
+
+    const MAJOR: &str = "3";
+    assert_semver_ge!(concat!(MAJOR, ".0.1"), "3.0.0");
+}
+
+#[test]
+fn concat_bitrev_smoke() {
+    use constcat::concat_bitrev;
+
+    const TEST0: &[u16] = concat_bitrev!(bits = 0);
+    assert_eq!(TEST0, [0]);
+
+    const TEST1: &[u16] = concat_bitrev!(bits = 1,);
+    assert_eq!(TEST1, [0, 1]);
+
+    const TEST2: &[u16] = concat_bitrev!(bits = 16);
+    assert_eq!(TEST2.len(), 65536);
+    assert_eq!(TEST2[1], 0x8000);
+    assert_eq!(TEST2[0x1234], 0x2C48);
+}
+
+#[test]
+fn concat_graycode_smoke() {
+    use constcat::concat_graycode;
+
+    const TEST0: &[u16] = concat_graycode!(bits = 0);
+    assert_eq!(TEST0, [0]);
+
+    const BITS: u32 = 3;
+    const TEST1: &[u16] = concat_graycode!(bits = BITS,);
+    assert_eq!(TEST1, [0, 1, 3, 2, 6, 7, 5, 4]);
+}
+
+#[test]
+fn assert_checksum_smoke() {
+    use constcat::{assert_checksum, concat_bytes};
+
+    assert_checksum!(&[0x00], index = 0, algo = xor);
+    assert_checksum!(&[0x00], index = 0, algo = sum,);
+
+    const BODY: &[u8] = &[0x80, 0x90, 0x01];
+    const IMAGE: &[u8] = concat_bytes!(&[0x11], BODY);
+    assert_checksum!(IMAGE, index = 0, algo = sum);
+    assert_checksum!(concat_bytes!(BODY, &[0x11]), index = 3, algo = xor);
+}
+
+#[test]
+fn concat_quoted_printable_smoke() {
+    use constcat::concat_quoted_printable;
+
+    const TEST0: &str = concat_quoted_printable!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_quoted_printable!("a\tb\t", "\r\n", "\n\r",);
+    assert_eq!(TEST1, "a\tb=09\r\n=0A=0D");
+
+    const LONG: &str = "0123456789012345678901234567890123456789012345678901234567890123456789";
+    const TEST2: &str = concat_quoted_printable!(LONG, "abcd=", "xyz");
+    assert_eq!(
+        TEST2,
+        "0123456789012345678901234567890123456789012345678901234567890123456789abcd=\r\n=3Dxyz"
+    );
+
+    const TEST3: &str = concat_quoted_printable!(LONG, "abcde", "f");
+    assert_eq!(
+        TEST3,
+        "0123456789012345678901234567890123456789012345678901234567890123456789abcde=\r\nf"
+    );
+}
+
+#[test]
+fn concat_mirror_smoke() {
+    use constcat::concat_mirror;
+
+    const TEST0: &[u8] = concat_mirror!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_mirror!([u8]: drop_middle = true;);
+    assert_eq!(TEST1, []);
+
+    const A: &[char] = &['a', 'b'];
+    const TEST2: &[char] = concat_mirror!([char]: A,);
+    assert_eq!(TEST2, ['a', 'b', 'b', 'a']);
+
+    const TEST3: &[char] = concat_mirror!([char]: drop_middle = false; A);
+    assert_eq!(TEST3, ['a', 'b', 'b', 'a']);
+
+    const TEST4: &[i32] = concat_mirror!([i32]: drop_middle = true; &[7]);
+    assert_eq!(TEST4, [7]);
+}
+
+#[test]
+fn concat_popcount_smoke() {
+    use constcat::concat_popcount;
+
+    const TEST0: usize = concat_popcount!([u8]:);
+    assert_eq!(TEST0, 0);
+
+    const MASKS: &[u64] = &[u64::MAX, 0b101];
+    const TEST1: usize = concat_popcount!([u64]: MASKS, &[1u64 << 63],);
+    assert_eq!(TEST1, 67);
+
+    const TEST2: usize = concat_popcount!([i16]: &[-1, 0]);
+    assert_eq!(TEST2, 16);
+}
+
+#[test]
+fn concat_braille_smoke() {
+    use constcat::concat_braille;
+
+    const TEST0: &str = concat_braille!();
+    assert_eq!(TEST0, "");
+
+    const TEST1: &str = concat_braille!(&[0x40, 0x80], &[0x3F],);
+    assert_eq!(TEST1, "\u{2840}\u{2880}\u{283F}");
+
+    const ALL: &[u8; 256] = &{
+        let mut arr = [0; 256];
+        let mut i = 0;
+        while i < 256 {
+            arr[i] = i as u8;
+            i += 1;
+        }
+        arr
+    };
+    const TEST2: &str = concat_braille!(ALL);
+    for (i, c) in TEST2.chars().enumerate() {
+        assert_eq!(c as u32, 0x2800 + i as u32);
+    }
+    assert_eq!(TEST2.chars().count(), 256);
+}
+
+#[test]
+fn concat_operands_sorted_smoke() {
+    use constcat::concat_operands_sorted;
+
+    const TEST0: &[u8] = concat_operands_sorted!([u8]:);
+    assert_eq!(TEST0, []);
+
+    const A: &[char] = &['a', 'z'];
+    const EMPTY: &[char] = &[];
+    const TEST1: &[char] = concat_operands_sorted!([char]: A, EMPTY, &['a'], &['b', 'a'],);
+    assert_eq!(TEST1, ['a', 'z', 'a', 'b', 'a']);
+}
+
+#[test]
+fn concat_grid_str_smoke() {
+    use constcat::concat_grid_str;
+
+    const TEST0: &str = concat_grid_str!(cols = 4;);
+    assert_eq!(TEST0, "");
+
+    const ROW: &[bool] = &[true, false];
+    const TEST1: &str = concat_grid_str!(cols = 2; ROW, ROW, &[false, true],);
+    assert_eq!(TEST1, "#.\n#.\n.#");
+
+    const TEST2: &str = concat_grid_str!(cols = 1, on = '1', off = '0'; ROW);
+    assert_eq!(TEST2, "1\n0");
+}
+
+#[test]
+fn concat_contiguous_smoke() {
+    use constcat::concat_contiguous;
+
+    const TEST0: &[u32] = concat_contiguous!([u32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_contiguous!([u8]: &[255]);
+    assert_eq!(TEST1, [255]);
+
+    const NEG: &[i8] = &[-128, -127];
+    const EMPTY: &[i8] = &[];
+    const TEST2: &[i8] = concat_contiguous!([i8]: NEG, EMPTY, &[-126],);
+    assert_eq!(TEST2, [-128, -127, -126]);
+}
+
+#[test]
+fn concat_prefix_compressed_smoke() {
+    use constcat::concat_prefix_compressed;
+
+    const TEST0: &[u8] = concat_prefix_compressed!();
+    assert_eq!(TEST0, []);
+
+    const A: &str = "ab";
+    const TEST1: &[u8] = concat_prefix_compressed!("", A, A, "abc", "b",);
+    const EXPECTED: &[u8] = &[0, 0, 0, 2, b'a', b'b', 2, 0, 2, 1, b'c', 0, 1, b'b'];
+    assert_eq!(TEST1, EXPECTED);
+}
+
+#[test]
+fn concat_prefix_decompress_smoke() {
+    use constcat::{concat_prefix_compressed, concat_prefix_decompress};
+
+    const TEST0: &[&str] = concat_prefix_decompress!();
+    assert_eq!(TEST0, [""; 0]);
+
+    const TABLE: &[u8] = concat_prefix_compressed!("", "ab", "ab", "abc", "b", "bé", "bél");
+    const TEST1: &[&str] = concat_prefix_decompress!(TABLE);
+    assert_eq!(TEST1, ["", "ab", "ab", "abc", "b", "bé", "bél"]);
+
+    const SPLIT: &[u8] = &[0, 2, b'x', b'y'];
+    const TEST2: &[&str] = concat_prefix_decompress!(SPLIT, &[1, 1, b'z'],);
+    assert_eq!(TEST2, ["xy", "xz"]);
+}
+
+#[test]
+fn concat_zigzag_smoke() {
+    use constcat::concat_zigzag;
+
+    const TEST0: &[u8] = concat_zigzag!([i8]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_zigzag!([i8]: &[i8::MIN, i8::MAX], &[-3],);
+    assert_eq!(TEST1, [0xFF, 0x01, 0xFE, 0x01, 0x05]);
+
+    const TEST2: &[u8] = concat_zigzag!([i64]: &[-150]);
+    assert_eq!(TEST2, [0xAB, 0x02]);
+
+    const TEST3: &[u8] = concat_zigzag!([i128]: &[i128::MIN]);
+    assert_eq!(TEST3.len(), 19);
+    assert_eq!(TEST3[18], 0x03);
+}
+
+#[test]
+fn concat_zigzag_decode_smoke() {
+    use constcat::{concat_zigzag, concat_zigzag_decode};
+
+    const TEST0: &[i32] = concat_zigzag_decode!([i32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[i8] = concat_zigzag_decode!([i8]: &[0xFF], &[0x01, 0x05],);
+    assert_eq!(TEST1, [i8::MIN, -3]);
+
+    const DATA: &[i128] = &[i128::MIN, -1, 0, 1, i128::MAX];
+    const TEST2: &[i128] = concat_zigzag_decode!([i128]: concat_zigzag!([i128]: DATA));
+    assert_eq!(TEST2, DATA);
+}
+
+#[test]
+fn concat_varint_smoke() {
+    use constcat::concat_varint;
+
+    const TEST0: &[u8] = concat_varint!([u32]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_varint!([u8]: &[128, 255], &[0],);
+    assert_eq!(TEST1, [0x80, 0x01, 0xFF, 0x01, 0x00]);
+
+    const TEST2: &[u8] = concat_varint!([u64]: &[u64::MAX]);
+    assert_eq!(TEST2.len(), 10);
+    assert_eq!(TEST2[9], 0x01);
+}
+
+#[test]
+fn concat_varint_decode_smoke() {
+    use constcat::{concat_varint, concat_varint_decode};
+
+    const TEST0: &[u64] = concat_varint_decode!([u64]:);
+    assert_eq!(TEST0, []);
+
+    const TEST1: &[u8] = concat_varint_decode!([u8]: &[0x80], &[0x01, 0xFF, 0x80, 0x00],);
+    assert_eq!(TEST1, [128, 127]);
+
+    const DATA: &[usize] = &[0, 1, 16384, usize::MAX];
+    const TEST2: &[usize] = concat_varint_decode!([usize]: concat_varint!([usize]: DATA));
+    assert_eq!(TEST2, DATA);
+}